@@ -6,9 +6,12 @@ extern crate darling;
 
 use core::panic;
 
-use syn::{parse_quote, parse_str, Expr, GenericParam, Generics, Ident, Meta, Path, Type};
+use syn::{
+    parse_quote, parse_str, Expr, GenericParam, Generics, Ident, ItemFn, Meta, Path, ReturnType,
+    Type,
+};
 
-use darling::{ast, FromDeriveInput};
+use darling::{ast, FromDeriveInput, FromMeta};
 use proc_macro::TokenStream;
 
 #[derive(Debug, FromVariant)]
@@ -52,36 +55,25 @@ struct DummyField {
 struct Dummy {
     ident: Ident,
     generics: Generics,
+    #[darling(default)]
+    stable_fields: bool,
+    #[darling(default)]
+    mixed_per_field: bool,
     data: ast::Data<DummyVariant, DummyField>,
 }
 
 #[proc_macro_derive(Dummy, attributes(dummy))]
 pub fn derive_dummy(input: TokenStream) -> TokenStream {
     let mut parsed: syn::DeriveInput = syn::parse(input).expect("syn::parse ok");
-
-    // Support custom crate name:
-    let mut crate_name = parse_quote!(::fake);
-    parsed.attrs.retain(|attr| {
-        if attr.path().is_ident("dummy") {
-            if let Ok(Meta::NameValue(meta_name_value)) = attr.parse_args::<Meta>() {
-                if meta_name_value.path.is_ident("crate_name") {
-                    if let Expr::Lit(lit) = meta_name_value.value {
-                        if let syn::Lit::Str(lit_str) = lit.lit {
-                            crate_name = parse_str(&lit_str.value()).expect("parse_str ok");
-                            return false;
-                        }
-                    }
-                }
-            }
-        }
-        true
-    });
+    let crate_name = take_crate_name(&mut parsed);
 
     let receiver = Dummy::from_derive_input(&parsed).expect("Dummy::from_derive_input ok");
 
     let generics = add_trait_bounds(&crate_name, receiver.generics);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let receiver_name = &receiver.ident;
+    let stable_fields = receiver.stable_fields;
+    let record = record_statement(&crate_name, receiver.mixed_per_field);
     let expanded = match receiver.data {
         darling::ast::Data::Struct(darling::ast::Fields {
             ref fields,
@@ -101,12 +93,19 @@ pub fn derive_dummy(input: TokenStream) -> TokenStream {
             ast::Style::Tuple => {
                 let tuple_fields: Vec<_> = fields
                     .iter()
-                    .map(|f| expose_field(&crate_name, f))
+                    .enumerate()
+                    .map(|(i, f)| {
+                        let stream = expose_field(&crate_name, f);
+                        field_stream(&crate_name, f, &i.to_string(), stream, stable_fields)
+                    })
                     .collect();
+                let field_seed = seed_statement(&crate_name, stable_fields);
 
                 let impl_dummy = quote! {
                     impl #impl_generics #crate_name::Dummy<#crate_name::Faker> for #receiver_name #ty_generics #where_clause {
                         fn dummy_with_rng<R: #crate_name::Rng + ?Sized>(_: &#crate_name::Faker, rng: &mut R) -> Self {
+                            #record
+                            #field_seed
                             #receiver_name(#(#tuple_fields),*)
                         }
                     }
@@ -123,15 +122,25 @@ pub fn derive_dummy(input: TokenStream) -> TokenStream {
                         let field_name = f.ident.as_ref().unwrap();
                         let field_ty = &f.ty;
                         let stream = expose_field(&crate_name, f);
+                        let stream = field_stream(
+                            &crate_name,
+                            f,
+                            &field_name.to_string(),
+                            stream,
+                            stable_fields,
+                        );
                         quote! {
                             let #field_name: #field_ty = #stream;
                         }
                     })
                     .collect();
+                let field_seed = seed_statement(&crate_name, stable_fields);
 
                 let impl_dummy = quote! {
                     impl #impl_generics #crate_name::Dummy<#crate_name::Faker> for #receiver_name #ty_generics #where_clause  {
                         fn dummy_with_rng<R: #crate_name::Rng + ?Sized>(_: &#crate_name::Faker, rng: &mut R) -> Self {
+                            #record
+                            #field_seed
                             #(#let_statements)*
                             #receiver_name {
                                 #(#struct_fields),*
@@ -165,11 +174,23 @@ pub fn derive_dummy(input: TokenStream) -> TokenStream {
                                     .fields
                                     .fields
                                     .iter()
-                                    .map(|f| expose_field(&crate_name, f))
+                                    .enumerate()
+                                    .map(|(i, f)| {
+                                        let stream = expose_field(&crate_name, f);
+                                        field_stream(
+                                            &crate_name,
+                                            f,
+                                            &i.to_string(),
+                                            stream,
+                                            stable_fields,
+                                        )
+                                    })
                                     .collect();
+                                let field_seed = seed_statement(&crate_name, stable_fields);
 
                                 quote! {
                                     #i => {
+                                        #field_seed
                                         #receiver_name::#variant_name(#(#tuple_fields),*)
                                     }
                                 }
@@ -190,14 +211,23 @@ pub fn derive_dummy(input: TokenStream) -> TokenStream {
                                         let field_name = f.ident.as_ref().unwrap();
                                         let field_ty = &f.ty;
                                         let stream = expose_field(&crate_name, f);
+                                        let stream = field_stream(
+                                            &crate_name,
+                                            f,
+                                            &field_name.to_string(),
+                                            stream,
+                                            stable_fields,
+                                        );
                                         quote! {
                                             let #field_name: #field_ty = #stream;
                                         }
                                     })
                                     .collect();
+                                let field_seed = seed_statement(&crate_name, stable_fields);
 
                                 quote! {
                                     #i => {
+                                        #field_seed
                                         #(#let_statements)*
                                         #receiver_name::#variant_name {
                                             #(#struct_fields),*
@@ -216,9 +246,10 @@ pub fn derive_dummy(input: TokenStream) -> TokenStream {
                 let impl_dummy = quote! {
                     impl #impl_generics #crate_name::Dummy<#crate_name::Faker> for #receiver_name #ty_generics #where_clause {
                         fn dummy_with_rng<R: #crate_name::Rng + ?Sized>(_: &#crate_name::Faker, rng: &mut R) -> Self {
+                            #record
                             let options = [#(#variant_opts),*];
                             match #crate_name::rand::seq::IndexedRandom::choose(
-                                <_ as ::std::convert::AsRef<[usize]>>::as_ref(&options),
+                                <_ as ::core::convert::AsRef<[usize]>>::as_ref(&options),
                                 rng,
                             )
                             .unwrap()
@@ -249,6 +280,114 @@ pub fn derive_dummy(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+#[proc_macro_derive(Enumerate, attributes(dummy))]
+pub fn derive_enumerate(input: TokenStream) -> TokenStream {
+    let mut parsed: syn::DeriveInput = syn::parse(input).expect("syn::parse ok");
+    let crate_name = take_crate_name(&mut parsed);
+
+    let receiver = Dummy::from_derive_input(&parsed).expect("Dummy::from_derive_input ok");
+    let (impl_generics, ty_generics, where_clause) = receiver.generics.split_for_impl();
+    let receiver_name = &receiver.ident;
+    let variants: Vec<_> = match receiver.data {
+        darling::ast::Data::Enum(ref variants) => variants
+            .iter()
+            .filter(|v| !v.skip)
+            .inspect(|v| {
+                if !v.fields.is_unit() {
+                    panic!("Enumerate can only be derived for enums without fields, unless the variant is skipped");
+                }
+            })
+            .map(|v| &v.ident)
+            .collect(),
+        darling::ast::Data::Struct(_) => panic!("Enumerate can only be derived for enums"),
+    };
+    let variant_count = variants.len();
+
+    let impl_enumerate = quote! {
+        impl #impl_generics #crate_name::Enumerate<#crate_name::Faker> for #receiver_name #ty_generics #where_clause {
+            type Iter = ::core::array::IntoIter<Self, #variant_count>;
+
+            fn enumerate(_: &#crate_name::Faker) -> Self::Iter {
+                ::core::iter::IntoIterator::into_iter([#(#receiver_name::#variants),*])
+            }
+        }
+    };
+    impl_enumerate.into()
+}
+
+/// Remove the `#[dummy(crate_name = "..")]` attribute, returning the path it
+/// sets or `::fake`.
+fn take_crate_name(parsed: &mut syn::DeriveInput) -> Path {
+    let mut crate_name = parse_quote!(::fake);
+    parsed.attrs.retain(|attr| {
+        if attr.path().is_ident("dummy") {
+            if let Ok(Meta::NameValue(meta_name_value)) = attr.parse_args::<Meta>() {
+                if meta_name_value.path.is_ident("crate_name") {
+                    if let Expr::Lit(lit) = meta_name_value.value {
+                        if let syn::Lit::Str(lit_str) = lit.lit {
+                            crate_name = parse_str(&lit_str.value()).expect("parse_str ok");
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    });
+    crate_name
+}
+
+#[derive(Debug, FromMeta)]
+struct TestArgs {
+    #[darling(default)]
+    runs: Option<u32>,
+    #[darling(default)]
+    crate_name: Option<String>,
+}
+
+#[proc_macro_attribute]
+pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let list =
+        ast::NestedMeta::parse_meta_list(attr.into()).expect("NestedMeta::parse_meta_list ok");
+    let args = TestArgs::from_list(&list).expect("TestArgs::from_list ok");
+    let crate_name: Path = match args.crate_name {
+        Some(ref name) => parse_str(name).expect("parse_str ok"),
+        None => parse_quote!(::fake),
+    };
+    let runs = args.runs.unwrap_or(1);
+
+    let mut inner: ItemFn = syn::parse(item).expect("syn::parse ok");
+    if inner.sig.asyncness.is_some() {
+        panic!("#[fake::test] does not support async functions");
+    }
+    let outer_attrs = core::mem::take(&mut inner.attrs);
+    let vis = &inner.vis;
+    let name = inner.sig.ident.clone();
+    inner.sig.ident = format_ident!("__fake_test");
+
+    let call = match inner.sig.inputs.len() {
+        0 => quote! { { let _ = rng; __fake_test() } },
+        1 => quote! { __fake_test(rng) },
+        _ => panic!("#[fake::test] functions take at most one argument, the RNG"),
+    };
+    // tests returning a `Result` fail on `Err` like a panic, so that the
+    // seed of the failing run is reported too
+    let call = match inner.sig.output {
+        ReturnType::Default => quote! { #call; },
+        ReturnType::Type(..) => quote! { ::core::result::Result::unwrap(#call); },
+    };
+
+    let expanded = quote! {
+        #[test]
+        #(#outer_attrs)*
+        #vis fn #name() {
+            #inner
+            #crate_name::seed::run_seeded(#runs, |rng| { #call });
+        }
+    };
+    expanded.into()
+}
+
 fn expose_field(crate_name: &Path, f: &DummyField) -> proc_macro2::TokenStream {
     if f.default {
         quote! {
@@ -267,7 +406,7 @@ fn expose_field(crate_name: &Path, f: &DummyField) -> proc_macro2::TokenStream {
             if let Some(ref from) = f.from {
                 let from_ty = syn::parse_str::<syn::Type>(from).unwrap();
                 quote! {
-                    ::std::convert::Into::<#field_ty>::into(#crate_name::Fake::fake_with_rng::<#from_ty, _>(&(#faker), rng))
+                    ::core::convert::Into::<#field_ty>::into(#crate_name::Fake::fake_with_rng::<#from_ty, _>(&(#faker), rng))
                 }
             } else if let Some(ref wrapper) = f.wrapper {
                 let wrapper_ty = syn::parse_str::<syn::Type>(wrapper).unwrap();
@@ -287,6 +426,49 @@ fn expose_field(crate_name: &Path, f: &DummyField) -> proc_macro2::TokenStream {
     }
 }
 
+/// Start a `MixedRecord` around the fields, unless `mixed_per_field`.
+fn record_statement(crate_name: &Path, mixed_per_field: bool) -> proc_macro2::TokenStream {
+    if mixed_per_field {
+        quote! {}
+    } else {
+        quote! {
+            let _record = #crate_name::locales::MixedRecord::start();
+        }
+    }
+}
+
+/// Draw the seed of the field RNGs from the parent RNG, with `stable_fields`.
+fn seed_statement(crate_name: &Path, stable_fields: bool) -> proc_macro2::TokenStream {
+    if stable_fields {
+        quote! {
+            let __field_seed = #crate_name::rand::RngCore::next_u64(rng);
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// With `stable_fields`, generate the field from its own RNG, seeded by the
+/// parent seed and the field name.
+fn field_stream(
+    crate_name: &Path,
+    f: &DummyField,
+    name: &str,
+    stream: proc_macro2::TokenStream,
+    stable_fields: bool,
+) -> proc_macro2::TokenStream {
+    if !stable_fields || f.default {
+        return stream;
+    }
+    quote! {
+        {
+            #[allow(unused_variables)]
+            let rng = &mut #crate_name::stable::field_rng(__field_seed, #name);
+            #stream
+        }
+    }
+}
+
 fn add_trait_bounds(crate_name: &Path, mut generics: Generics) -> Generics {
     for param in &mut generics.params {
         if let GenericParam::Type(ref mut type_param) = *param {
@@ -1,6 +1,7 @@
 use fake::faker::phone_number::en::{CellNumber, PhoneNumber};
 use fake::{
-    utils::{either, WrappedVal},
+    one_of,
+    utils::{either, Choice, WrappedVal},
     Dummy, Fake, Faker,
 };
 
@@ -9,6 +10,12 @@ struct Foo {
     #[allow(unused)]
     #[dummy(faker = "either(PhoneNumber(), CellNumber())", wrapper = "WrappedVal")]
     phone_number: String,
+    #[allow(unused)]
+    #[dummy(faker = "one_of![70 => CellNumber(), 30 => PhoneNumber()]")]
+    weighted_phone_number: String,
+    #[allow(unused)]
+    #[dummy(faker = "Choice::new(&[\"free\", \"pro\", \"enterprise\"]).weights([80, 15, 5])")]
+    plan: &'static str,
 }
 
 fn main() {
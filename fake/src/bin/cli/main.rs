@@ -1,14 +1,12 @@
 use clap::{command, value_parser, Arg};
-use rand::Rng;
+use fake::locales::Locale;
+use fake::registry::{DynFaker, Value};
+use fake::Fake;
 use std::io::{self, Write};
 
 mod fake_gen;
 
-const AVAILABLE_LOCALES: [&str; 10] = [
-    "en", "fr_fr", "zh_tw", "zh_cn", "ja_jp", "ar_sa", "pt_br", "pt_pt", "de_de", "it_it",
-];
-
-pub use fake_gen::{all_fakegen_commands, AVAILABLE_LOCALES};
+pub use fake_gen::{all_fakegen_commands, fake_generator};
 pub fn main() {
     let stdout = io::stdout();
     let mut buf_stdout = io::BufWriter::new(stdout);
@@ -18,37 +16,23 @@ pub fn main() {
 
     writeln!(
         buf_stdout,
-        "Generating {} fakes for {:?} locale",
-        args.0.repeats, args.0.locale
+        "Generating {} fakes for {} locale",
+        args.repeats, args.locale
     )
     .unwrap();
 
-    (0..args.0.repeats).for_each(|_| writeln!(buf_stdout, "{}", args.1(&mut thread_rng)).unwrap());
-}
-
-impl TryFrom<&str> for AVAILABLE_LOCALES {
-    type Error = String;
-    fn try_from(str_val: &str) -> Result<Self, Self::Error> {
-        let str_val = str_val.to_lowercase();
-        let variant =  match str_val.as_str(){
-            "en" => AVAILABLE_LOCALES::EN,
-            "fr_fr" => AVAILABLE_LOCALES::FR_FR,
-            "zh_tw" => AVAILABLE_LOCALES::ZH_TW,
-            "zh_cn" => AVAILABLE_LOCALES::ZH_CN,
-            "ja_jp" => AVAILABLE_LOCALES::JA_JP,
-            "ar_sa" => AVAILABLE_LOCALES::AR_SA,
-            "pt_br" => AVAILABLE_LOCALES::PT_BR,
-            "pt_pt" => AVAILABLE_LOCALES::PT_PT,
-            "de_de" => AVAILABLE_LOCALES::DE_DE,
-            "it_it" => AVAILABLE_LOCALES::IT_IT,
-            _=> return Err(format!("{} is either an invalid locale or not yet supported.\n The supported locales are: {:?}",str_val,AVAILABLE_LOCALES))
-        };
-        Ok(variant)
-    }
+    (0..args.repeats).for_each(|_| {
+        match args.faker.fake_with_rng(&mut thread_rng) {
+            Value::String(s) => writeln!(buf_stdout, "{}", s),
+            Value::Bool(b) => writeln!(buf_stdout, "{}", b),
+            Value::Float(f) => writeln!(buf_stdout, "{}", f),
+            Value::List(items) => writeln!(buf_stdout, "{:?}", items),
+        }
+        .unwrap()
+    });
 }
 
-fn cli_parser<R: Rng>() -> (Args, impl Fn(&mut R) -> String) {
-    let (subcommands, fake_generator) = all_fakegen_commands::<R>();
+fn cli_parser() -> Args {
     let mut command = command!()
         .arg(
             Arg::new("repeat")
@@ -62,23 +46,31 @@ fn cli_parser<R: Rng>() -> (Args, impl Fn(&mut R) -> String) {
                 .short('l')
                 .long("locale")
                 .default_value("EN")
-                .value_parser(|value: &str| AVAILABLE_LOCALES::try_from(value)),
+                .value_parser(value_parser!(Locale)),
         )
-        .subcommands(subcommands)
+        .subcommands(all_fakegen_commands())
         .arg_required_else_help(true);
     let help_message = command.render_help();
     let matches = command.get_matches();
     let repeats = *matches.get_one::<u32>("repeat").unwrap();
-    let locale = matches
-        .get_one::<AVAILABLE_LOCALES>("locale")
-        .unwrap()
-        .to_owned();
+    let locale = *matches.get_one::<Locale>("locale").unwrap();
 
-    let fake_gen = fake_generator(matches, locale, help_message);
-    (Args { repeats, locale }, fake_gen)
+    let faker = match fake_generator(&matches, locale) {
+        Ok(faker) => faker,
+        Err(e) => {
+            println!("{}\n {}", e, help_message);
+            std::process::exit(0)
+        }
+    };
+    Args {
+        repeats,
+        locale,
+        faker,
+    }
 }
 
 struct Args {
     repeats: u32,
-    locale: AVAILABLE_LOCALES,
+    locale: Locale,
+    faker: DynFaker,
 }
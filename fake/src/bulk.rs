@@ -0,0 +1,117 @@
+//! Bulk generation with per-item derived seeds.
+//!
+//! Item `index` of a run seeded with `seed` is always generated from
+//! [`item_rng(seed, index)`](item_rng), independently of the other items.
+//! Any single record can thus be regenerated with [`generate_at`], and
+//! [`par_generate`] (behind the `rayon` feature) produces exactly the same
+//! output as [`generate`] whatever the number of threads.
+//!
+//! The [`FakerConfig`](crate::FakerConfig) of the calling thread is applied to every item,
+//! and so is [stable mode](crate::stable) if it is enabled there.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::bulk;
+//! use fake::faker::name::en::Name;
+//!
+//! let names: Vec<String> = bulk::generate(&Name(), 100, 42);
+//! let name_7: String = bulk::generate_at(&Name(), 42, 7);
+//! assert_eq!(names[7], name_7);
+//! ```
+
+use crate::Dummy;
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// RNG for item `index` of a run seeded with `seed`.
+///
+/// This is a [`StdRng`] in [stable mode](crate::stable) too: items sample
+/// with the stable algorithms, but the stream of `StdRng` may change with
+/// `rand`, unlike the one of [`StableRng`](crate::stable::StableRng).
+pub fn item_rng(seed: u64, index: u64) -> StdRng {
+    StdRng::seed_from_u64(mix(seed, index))
+}
+
+// SplitMix64 finalizer over the seed and the spread index.
+fn mix(seed: u64, index: u64) -> u64 {
+    let mut z = seed ^ index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Generate item `index` of a run seeded with `seed`.
+pub fn generate_at<T, C>(config: &C, seed: u64, index: u64) -> T
+where
+    T: Dummy<C>,
+{
+    T::dummy_with_rng(config, &mut item_rng(seed, index))
+}
+
+/// Generate `n` items seeded with `seed` on the current thread.
+pub fn generate<T, C>(config: &C, n: usize, seed: u64) -> Vec<T>
+where
+    T: Dummy<C>,
+{
+    (0..n)
+        .map(|i| generate_at(config, seed, i as u64))
+        .collect()
+}
+
+/// Generate `n` items seeded with `seed` in parallel.
+///
+/// The output is identical to [`generate`] with the same arguments.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Dummy, Faker};
+///
+/// #[derive(Debug, PartialEq, Dummy)]
+/// struct Row {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// let rows: Vec<Row> = fake::par_generate(&Faker, 10_000, 7);
+/// assert_eq!(rows[1234], fake::bulk::generate_at(&Faker, 7, 1234));
+/// ```
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn par_generate<T, C>(config: &C, n: usize, seed: u64) -> Vec<T>
+where
+    T: Dummy<C> + Send,
+    C: Sync,
+{
+    use rayon::prelude::*;
+
+    par_iter(config, n, seed).collect()
+}
+
+/// Indexed parallel iterator over `n` items seeded with `seed`.
+///
+/// Use it instead of [`par_generate`] to stream items into a writer without
+/// collecting them first.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn par_iter<T, C>(
+    config: &C,
+    n: usize,
+    seed: u64,
+) -> impl rayon::iter::IndexedParallelIterator<Item = T> + '_
+where
+    T: Dummy<C> + Send,
+    C: Sync,
+{
+    use rayon::prelude::*;
+
+    let faker_config = crate::FakerConfig::current();
+    let stable = crate::stable::is_enabled();
+    (0..n).into_par_iter().map(move |i| {
+        let _guard = faker_config.scoped();
+        let _stable = stable.then(crate::stable::enable);
+        generate_at(config, seed, i as u64)
+    })
+}
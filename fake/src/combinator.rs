@@ -0,0 +1,256 @@
+//! Combinators building new fakers out of existing ones.
+//!
+//! [`FakerExt`] is implemented for every config and provides
+//! [`fake_map`](FakerExt::fake_map), [`fake_filter`](FakerExt::fake_filter)
+//! and [`fake_and_then`](FakerExt::fake_and_then). [`from_fn`] and
+//! [`constant`] create fakers from a closure or a fixed value.
+//!
+//! See [`WrappedVal`] for the types the resulting fakers generate directly.
+//!
+//! The methods carry a `fake_` prefix so that they do not clash with
+//! [`Iterator::map`] and friends on configs which are also iterators, like
+//! ranges.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::combinator::{constant, from_fn, FakerExt};
+//! use fake::faker::internet::en::SafeEmail;
+//! use fake::faker::lorem::en::Words;
+//! use fake::faker::name::en::Name;
+//! use fake::{Dummy, Fake, Faker, Rng};
+//!
+//! #[derive(Debug, Dummy)]
+//! struct User {
+//!     #[dummy(faker = "Name().fake_map(|s: String| s.to_uppercase())")]
+//!     name: String,
+//!     #[dummy(faker = "SafeEmail().fake_filter(|s: &String| s.ends_with(\".com\"))")]
+//!     email: String,
+//!     #[dummy(faker = "from_fn(|rng| rng.random_range(18..=99))")]
+//!     age: u8,
+//!     #[dummy(faker = "constant(\"active\")")]
+//!     status: &'static str,
+//!     #[dummy(faker = "(1..4usize).fake_map(|n: usize| n * 2).fake_and_then(|n: usize| Words(n..n + 1))")]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let user: User = Faker.fake();
+//! assert_eq!(user.name, user.name.to_uppercase());
+//! assert!(user.email.ends_with(".com"));
+//! assert!(user.tags.len() % 2 == 0);
+//! ```
+
+use crate::utils::WrappedVal;
+use crate::{Dummy, Fake};
+use alloc::{string::String, vec::Vec};
+use core::marker::PhantomData;
+use rand::{Rng, RngCore};
+
+const DEFAULT_FILTER_RETRIES: usize = 100;
+
+/// Combinators available on every config.
+pub trait FakerExt: Sized {
+    /// Transform each generated value with `f`.
+    fn fake_map<T, U, F>(self, f: F) -> Map<Self, F, T>
+    where
+        F: Fn(T) -> U,
+    {
+        Map {
+            faker: self,
+            f,
+            _input: PhantomData,
+        }
+    }
+
+    /// Regenerate values until `pred` accepts one.
+    ///
+    /// Panics after 100 consecutive rejections, see [`Filter::retries`].
+    fn fake_filter<T, P>(self, pred: P) -> Filter<Self, P, T>
+    where
+        P: Fn(&T) -> bool,
+    {
+        Filter {
+            faker: self,
+            pred,
+            retries: DEFAULT_FILTER_RETRIES,
+            _input: PhantomData,
+        }
+    }
+
+    /// Build a second faker out of each generated value and use it.
+    fn fake_and_then<T, C, F>(self, f: F) -> AndThen<Self, F, T>
+    where
+        F: Fn(T) -> C,
+    {
+        AndThen {
+            faker: self,
+            f,
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<C> FakerExt for C {}
+
+/// Faker created by [`FakerExt::fake_map`].
+pub struct Map<C, F, T> {
+    faker: C,
+    f: F,
+    _input: PhantomData<fn() -> T>,
+}
+
+impl<C, F, T> Map<C, F, T> {
+    fn generate<U, R: Rng + ?Sized>(&self, rng: &mut R) -> U
+    where
+        T: Dummy<C>,
+        F: Fn(T) -> U,
+    {
+        (self.f)(self.faker.fake_with_rng(rng))
+    }
+}
+
+/// Faker created by [`FakerExt::fake_filter`].
+pub struct Filter<C, P, T> {
+    faker: C,
+    pred: P,
+    retries: usize,
+    _input: PhantomData<fn() -> T>,
+}
+
+impl<C, P, T> Filter<C, P, T> {
+    /// Number of consecutive rejections tolerated before panicking.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> T
+    where
+        T: Dummy<C>,
+        P: Fn(&T) -> bool,
+    {
+        for _ in 0..=self.retries {
+            let value: T = self.faker.fake_with_rng(rng);
+            if (self.pred)(&value) {
+                return value;
+            }
+        }
+        panic!(
+            "filter rejected {} consecutive values",
+            self.retries.saturating_add(1)
+        )
+    }
+}
+
+/// Faker created by [`FakerExt::fake_and_then`].
+pub struct AndThen<C, F, T> {
+    faker: C,
+    f: F,
+    _input: PhantomData<fn() -> T>,
+}
+
+impl<C, F, T> AndThen<C, F, T> {
+    fn generate<C2, U, R: Rng + ?Sized>(&self, rng: &mut R) -> U
+    where
+        T: Dummy<C>,
+        F: Fn(T) -> C2,
+        U: Dummy<C2>,
+    {
+        let next = (self.f)(self.faker.fake_with_rng(rng));
+        next.fake_with_rng(rng)
+    }
+}
+
+/// Faker created by [`from_fn`].
+pub struct FromFn<F>(F);
+
+/// Create a faker out of a closure receiving the RNG.
+pub fn from_fn<U, F>(f: F) -> FromFn<F>
+where
+    F: Fn(&mut dyn RngCore) -> U,
+{
+    FromFn(f)
+}
+
+impl<F> FromFn<F> {
+    fn generate<U, R: Rng + ?Sized>(&self, rng: &mut R) -> U
+    where
+        F: Fn(&mut dyn RngCore) -> U,
+    {
+        let mut rng = rng;
+        (self.0)(&mut rng)
+    }
+}
+
+/// Faker created by [`constant`].
+pub struct Constant<T>(T);
+
+/// Create a faker always producing a clone of `value`.
+pub fn constant<T: Clone>(value: T) -> Constant<T> {
+    Constant(value)
+}
+
+macro_rules! combinator_impl {
+    ([$($gen:tt)*] $typ:ty => $inner:ty, $wrap:expr) => {
+        impl<$($gen)* C, F, T> Dummy<Map<C, F, T>> for $typ
+        where
+            T: Dummy<C>,
+            F: Fn(T) -> $inner,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &Map<C, F, T>, rng: &mut R) -> Self {
+                $wrap(config.generate(rng))
+            }
+        }
+
+        impl<$($gen)* C, P> Dummy<Filter<C, P, $inner>> for $typ
+        where
+            $inner: Dummy<C>,
+            P: Fn(&$inner) -> bool,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &Filter<C, P, $inner>, rng: &mut R) -> Self {
+                $wrap(config.generate(rng))
+            }
+        }
+
+        impl<$($gen)* C, C2, F, T> Dummy<AndThen<C, F, T>> for $typ
+        where
+            T: Dummy<C>,
+            F: Fn(T) -> C2,
+            $inner: Dummy<C2>,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &AndThen<C, F, T>, rng: &mut R) -> Self {
+                $wrap(config.generate::<C2, $inner, R>(rng))
+            }
+        }
+
+        impl<$($gen)* F> Dummy<FromFn<F>> for $typ
+        where
+            F: Fn(&mut dyn RngCore) -> $inner,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &FromFn<F>, rng: &mut R) -> Self {
+                $wrap(config.generate(rng))
+            }
+        }
+
+        impl<$($gen)*> Dummy<Constant<$inner>> for $typ
+        where
+            $inner: Clone,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &Constant<$inner>, _: &mut R) -> Self {
+                $wrap(config.0.clone())
+            }
+        }
+    };
+    ($($typ:ty),*) => {
+        $(
+            combinator_impl!([] $typ => $typ, core::convert::identity);
+        )*
+    };
+}
+
+combinator_impl!(
+    String, bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+combinator_impl!(['a,] &'a str => &'a str, core::convert::identity);
+combinator_impl!([X,] Vec<X> => Vec<X>, core::convert::identity);
+combinator_impl!([X,] WrappedVal<X> => X, WrappedVal::new);
@@ -0,0 +1,177 @@
+//! Runtime defaults for values generated with [`Faker`](crate::Faker).
+//!
+//! The length of [`String`]s, the length of collections and the chance of an
+//! [`Option`] being [`None`] are read from the [`FakerConfig`] of the current
+//! thread. Since every nested `Faker` generation reads the same settings, they
+//! also apply to e.g. a `Vec<Option<String>>` inside a derived struct.
+//!
+//! Without the `std` feature there is no thread-local config and the default
+//! one is always used.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::{Dummy, Fake, Faker, FakerConfig};
+//!
+//! #[derive(Debug, Dummy)]
+//! struct Foo {
+//!     tags: Vec<Option<String>>,
+//! }
+//!
+//! let config = FakerConfig::default()
+//!     .collection_len(1..3)
+//!     .string_len(8..=8)
+//!     .none_ratio(10);
+//!
+//! let foo: Foo = config.fake();
+//! assert!((1..3).contains(&foo.tags.len()));
+//! for tag in foo.tags.iter().flatten() {
+//!     assert_eq!(tag.len(), 8);
+//! }
+//!
+//! // or apply it to everything generated on this thread for a while
+//! let _guard = config.scoped();
+//! let names: Vec<String> = Faker.fake();
+//! ```
+
+#[cfg(feature = "std")]
+use crate::{Dummy, Fake, Faker};
+use core::ops::{Bound, Range, RangeBounds};
+#[cfg(feature = "std")]
+use rand::Rng;
+#[cfg(feature = "std")]
+use std::cell::Cell;
+#[cfg(feature = "std")]
+use std::marker::PhantomData;
+
+const DEFAULT_STR_LEN_RANGE: Range<usize> = 5..20;
+const DEFAULT_LEN_RANGE: Range<usize> = 0..10;
+const DEFAULT_NONE_RATIO: u8 = 50;
+
+/// Settings applied to values generated with [`Faker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FakerConfig {
+    string_len: (usize, usize),
+    collection_len: (usize, usize),
+    none_ratio: u8,
+}
+
+impl Default for FakerConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static CURRENT: Cell<FakerConfig> = const { Cell::new(FakerConfig::DEFAULT) };
+}
+
+fn to_range<B: RangeBounds<usize>>(bounds: B) -> (usize, usize) {
+    let start = match bounds.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(&n) => n.saturating_add(1),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => panic!("length range must have an upper bound"),
+    };
+    assert!(start < end, "length range must not be empty");
+    (start, end)
+}
+
+impl FakerConfig {
+    pub const DEFAULT: FakerConfig = FakerConfig {
+        string_len: (DEFAULT_STR_LEN_RANGE.start, DEFAULT_STR_LEN_RANGE.end),
+        collection_len: (DEFAULT_LEN_RANGE.start, DEFAULT_LEN_RANGE.end),
+        none_ratio: DEFAULT_NONE_RATIO,
+    };
+
+    /// Length range of generated [`String`]s, defaults to `5..20`.
+    pub fn string_len<B: RangeBounds<usize>>(mut self, len: B) -> Self {
+        self.string_len = to_range(len);
+        self
+    }
+
+    /// Length range of generated collections, defaults to `0..10`.
+    pub fn collection_len<B: RangeBounds<usize>>(mut self, len: B) -> Self {
+        self.collection_len = to_range(len);
+        self
+    }
+
+    /// Percentage of generated [`Option`]s being [`None`], defaults to `50`.
+    pub fn none_ratio(mut self, ratio: u8) -> Self {
+        self.none_ratio = ratio.min(100);
+        self
+    }
+
+    pub fn string_len_range(&self) -> Range<usize> {
+        self.string_len.0..self.string_len.1
+    }
+
+    pub fn collection_len_range(&self) -> Range<usize> {
+        self.collection_len.0..self.collection_len.1
+    }
+
+    pub fn none_ratio_percent(&self) -> u8 {
+        self.none_ratio
+    }
+
+    /// Config of the current thread.
+    #[cfg(feature = "std")]
+    pub fn current() -> FakerConfig {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Default config, as there is no thread-local one without `std`.
+    #[cfg(not(feature = "std"))]
+    pub fn current() -> FakerConfig {
+        Self::DEFAULT
+    }
+
+    /// Use this config on the current thread from now on.
+    #[cfg(feature = "std")]
+    pub fn set(self) {
+        CURRENT.with(|c| c.set(self));
+    }
+
+    /// Use this config on the current thread until the guard is dropped.
+    #[cfg(feature = "std")]
+    pub fn scoped(self) -> ConfigGuard {
+        ConfigGuard {
+            prev: CURRENT.with(|c| c.replace(self)),
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Generate a value with [`Faker`] under this config.
+    #[cfg(feature = "std")]
+    pub fn fake<U: Dummy<Faker>>(&self) -> U {
+        let _guard = self.scoped();
+        Faker.fake()
+    }
+
+    /// Generate a value with [`Faker`] under this config using `rng`.
+    #[cfg(feature = "std")]
+    pub fn fake_with_rng<U: Dummy<Faker>, R: Rng + ?Sized>(&self, rng: &mut R) -> U {
+        let _guard = self.scoped();
+        Faker.fake_with_rng(rng)
+    }
+}
+
+/// Guard returned by [`FakerConfig::scoped`].
+#[cfg(feature = "std")]
+#[must_use = "the config is reset as soon as the guard is dropped"]
+pub struct ConfigGuard {
+    prev: FakerConfig,
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for ConfigGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|c| c.set(self.prev));
+    }
+}
@@ -0,0 +1,171 @@
+//! Non-uniform distributions for numeric values.
+//!
+//! Ranges generate uniformly distributed numbers. The configs of this module
+//! follow other distributions, which is closer to real data like salaries,
+//! latencies or order counts. They generate every integer and float type.
+//!
+//! Samples are clamped to the optional [`min`](Normal::min) and
+//! [`max`](Normal::max) bounds. Integers are then rounded to the nearest value,
+//! saturating at the bounds of the type, so e.g. negative samples give `0` for
+//! unsigned types.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::distribution::{LogNormal, Normal, Poisson, Zipf};
+//! use fake::{Dummy, Fake, Faker};
+//!
+//! #[derive(Debug, Dummy)]
+//! struct Employee {
+//!     #[dummy(faker = "Normal::new(60_000.0, 15_000.0).min(20_000.0)")]
+//!     salary: u32,
+//!     #[dummy(faker = "LogNormal::new(3.0, 0.5).max(500.0)")]
+//!     latency_ms: f64,
+//!     #[dummy(faker = "Poisson::new(4.0)")]
+//!     orders: u16,
+//!     #[dummy(faker = "Zipf::new(100, 1.2)")]
+//!     product_rank: u8,
+//! }
+//!
+//! let e: Employee = Faker.fake();
+//! assert!(e.salary >= 20_000);
+//! assert!(e.latency_ms <= 500.0);
+//! assert!((1..=100).contains(&e.product_rank));
+//! ```
+
+use crate::Dummy;
+use rand::distr::Distribution;
+use rand::Rng;
+use rand_distr as distr;
+
+macro_rules! distribution {
+    ($(#[$doc:meta])* $name:ident($inner:ty)) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name {
+            dist: $inner,
+            min: f64,
+            max: f64,
+        }
+
+        impl $name {
+            fn from_dist(dist: $inner) -> Self {
+                $name {
+                    dist,
+                    min: f64::NEG_INFINITY,
+                    max: f64::INFINITY,
+                }
+            }
+
+            /// Clamp samples to at least `min`.
+            pub fn min(mut self, min: f64) -> Self {
+                self.min = min;
+                self
+            }
+
+            /// Clamp samples to at most `max`.
+            pub fn max(mut self, max: f64) -> Self {
+                self.max = max;
+                self
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+                let v: f64 = self.dist.sample(rng);
+                v.max(self.min).min(self.max)
+            }
+        }
+
+        distribution_impl!($name: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+        impl Dummy<$name> for f32 {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &$name, rng: &mut R) -> Self {
+                config.sample(rng) as f32
+            }
+        }
+
+        impl Dummy<$name> for f64 {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &$name, rng: &mut R) -> Self {
+                config.sample(rng)
+            }
+        }
+    };
+}
+
+macro_rules! distribution_impl {
+    ($name:ident: $($typ:ty),*) => {
+        $(
+            impl Dummy<$name> for $typ {
+                fn dummy_with_rng<R: Rng + ?Sized>(config: &$name, rng: &mut R) -> Self {
+                    // `f64::round` needs std, float to integer casts truncate
+                    // towards zero and saturate
+                    let v = config.sample(rng);
+                    (if v < 0.0 { v - 0.5 } else { v + 0.5 }) as $typ
+                }
+            }
+        )*
+    };
+}
+
+distribution! {
+    /// Normal distribution with the given mean and standard deviation.
+    Normal(distr::Normal<f64>)
+}
+
+impl Normal {
+    /// Panics if `std_dev` is negative or not finite.
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        Self::from_dist(distr::Normal::new(mean, std_dev).expect("valid normal distribution"))
+    }
+}
+
+distribution! {
+    /// Log-normal distribution, whose logarithm is normal with mean `mu` and
+    /// standard deviation `sigma`.
+    LogNormal(distr::LogNormal<f64>)
+}
+
+impl LogNormal {
+    /// Panics if `sigma` is negative or not finite.
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        Self::from_dist(distr::LogNormal::new(mu, sigma).expect("valid log-normal distribution"))
+    }
+}
+
+distribution! {
+    /// Exponential distribution with rate `lambda`, i.e. a mean of
+    /// `1 / lambda`.
+    Exponential(distr::Exp<f64>)
+}
+
+impl Exponential {
+    /// Panics if `lambda` is negative or NaN.
+    pub fn new(lambda: f64) -> Self {
+        Self::from_dist(distr::Exp::new(lambda).expect("valid exponential distribution"))
+    }
+}
+
+distribution! {
+    /// Poisson distribution with mean `lambda`, the number of events in an
+    /// interval.
+    Poisson(distr::Poisson<f64>)
+}
+
+impl Poisson {
+    /// Panics if `lambda` is not positive or not finite.
+    pub fn new(lambda: f64) -> Self {
+        Self::from_dist(distr::Poisson::new(lambda).expect("valid poisson distribution"))
+    }
+}
+
+distribution! {
+    /// Zipf distribution over the ranks `1..=n` with exponent `s`, rank `k`
+    /// having a weight of `1 / k^s`.
+    Zipf(distr::Zipf<f64>)
+}
+
+impl Zipf {
+    /// Panics if `n` is `0` or `s` is negative or NaN.
+    pub fn new(n: u64, s: f64) -> Self {
+        Self::from_dist(distr::Zipf::new(n as f64, s).expect("valid zipf distribution"))
+    }
+}
@@ -0,0 +1,85 @@
+use alloc::string::String;
+use core::{iter, slice};
+
+/// List every value of a finite domain instead of sampling it.
+///
+/// The enumerating counterpart of [`Dummy`](crate::Dummy): a type
+/// implements `Enumerate<T>` when the values it can generate with the config
+/// `T` can all be listed, like those of the fakers picking from a locale
+/// table, e.g. [`StateAbbr`](crate::faker::address::en::StateAbbr) or
+/// [`CurrencyCode`](crate::faker::currency::en::CurrencyCode), or the
+/// variants of a fieldless enum deriving `Enumerate`. Values come in a fixed
+/// order, once per entry of the underlying table.
+///
+/// The values are listed with [`Fake::fake_all`](crate::Fake::fake_all).
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::currency::en::CurrencyCode;
+/// use fake::Fake;
+///
+/// for code in CurrencyCode().fake_all::<&str>() {
+///     assert_eq!(code.len(), 3);
+/// }
+/// ```
+///
+/// # Derivable
+///
+/// The trait can be derived for enums without fields, listing their
+/// variants in declaration order, except the ones marked with
+/// `#[dummy(skip)]`, which [`Dummy`](crate::Dummy) never generates either.
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use fake::{Dummy, Enumerate, Fake, Faker};
+///
+/// #[derive(Debug, PartialEq, Dummy, Enumerate)]
+/// enum Status {
+///     Active,
+///     Suspended,
+///     #[dummy(skip)]
+///     Deleted,
+/// }
+///
+/// let all: Vec<Status> = Faker.fake_all::<Status>().collect();
+/// assert_eq!(all, [Status::Active, Status::Suspended]);
+/// # }
+/// ```
+pub trait Enumerate<T>: Sized {
+    /// Iterator over the values.
+    type Iter: Iterator<Item = Self>;
+
+    /// Every value that can be generated with `config`.
+    fn enumerate(config: &T) -> Self::Iter;
+}
+
+/// Iterator over the strings of a locale table.
+pub type Table = iter::Copied<slice::Iter<'static, &'static str>>;
+
+/// Iterator over the strings of a locale table, as `String`s.
+pub type TableStrings = iter::Map<slice::Iter<'static, &'static str>, fn(&&'static str) -> String>;
+
+pub(crate) fn table(table: &'static [&'static str]) -> Table {
+    table.iter().copied()
+}
+
+pub(crate) fn table_strings(table: &'static [&'static str]) -> TableStrings {
+    table.iter().map(|s| String::from(*s))
+}
+
+impl Enumerate<crate::Faker> for bool {
+    type Iter = core::array::IntoIter<bool, 2>;
+
+    fn enumerate(_: &crate::Faker) -> Self::Iter {
+        IntoIterator::into_iter([false, true])
+    }
+}
+
+impl Enumerate<crate::Faker> for () {
+    type Iter = iter::Once<()>;
+
+    fn enumerate(_: &crate::Faker) -> Self::Iter {
+        iter::once(())
+    }
+}
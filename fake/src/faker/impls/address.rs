@@ -1,41 +1,75 @@
 use crate::faker::address::raw::*;
 use crate::faker::name::raw::{FirstName, LastName, Name};
-use crate::faker::numerify_sym;
-use crate::locales::Data;
-use crate::{Dummy, Fake, Faker};
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, write_numerified, write_template};
+use crate::{Dummy, Fake, FakeWrite, Faker};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<CityPrefix<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CityPrefix<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_CITY_PREFIX.choose(rng).unwrap();
+impl<L: Tables> Dummy<CityPrefix<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CityPrefix<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_CITY_PREFIX.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CityPrefix<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CityPrefix<L>, rng: &mut R) -> Self {
-        L::ADDRESS_CITY_PREFIX.choose(rng).unwrap()
+impl<L: Tables> Dummy<CityPrefix<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CityPrefix<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_CITY_PREFIX.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<CitySuffix<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CitySuffix<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_CITY_SUFFIX.choose(rng).unwrap();
+impl<L: Tables> Dummy<CitySuffix<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CitySuffix<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_CITY_SUFFIX.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CitySuffix<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CitySuffix<L>, rng: &mut R) -> Self {
-        L::ADDRESS_CITY_SUFFIX.choose(rng).unwrap()
+impl<L: Tables> Dummy<CitySuffix<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CitySuffix<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_CITY_SUFFIX.choose(rng).unwrap()
     }
 }
 
-pub trait CityNameGenFn: Data + Sized + Copy {
+write_str_impl!(
+    CityPrefix,
+    CitySuffix,
+    CountryName,
+    CountryCode,
+    StreetSuffix,
+    TimeZone,
+    StateName,
+    StateAbbr,
+    SecondaryAddressType
+);
+
+enumerate_table_impl!(
+    CityPrefix => ADDRESS_CITY_PREFIX,
+    CitySuffix => ADDRESS_CITY_SUFFIX,
+    CountryName => ADDRESS_COUNTRY,
+    CountryCode => ADDRESS_COUNTRY_CODE,
+    StreetSuffix => ADDRESS_STREET_SUFFIX,
+    TimeZone => ADDRESS_TIME_ZONE,
+    StateName => ADDRESS_STATE,
+    StateAbbr => ADDRESS_STATE_ABBR,
+    SecondaryAddressType => ADDRESS_SECONDARY_ADDR_TYPE,
+);
+
+pub trait CityNameGenFn: Tables + Sized + Copy {
     fn gen<R: Rng + ?Sized>(c: &CityName<Self>, rng: &mut R) -> String {
-        match (0..5).fake_with_rng::<u8, _>(rng) {
-            0 => Self::ADDRESS_CITY_WITH_PREFIX_TPL
+        city_name(c, rng)
+    }
+}
+
+/// City name built from the `ADDRESS_CITY_*` templates of the locale.
+pub(crate) fn city_name<L: Tables + Copy, R: Rng + ?Sized>(c: &CityName<L>, rng: &mut R) -> String {
+    match (0..5).fake_with_rng::<u8, _>(rng) {
+        0 => {
+            c.0.tables()
+                .ADDRESS_CITY_WITH_PREFIX_TPL
                 .replace(
                     "{CityPrefix}",
                     CityPrefix(c.0).fake_with_rng::<&str, _>(rng),
@@ -47,19 +81,25 @@ pub trait CityNameGenFn: Data + Sized + Copy {
                 .replace(
                     "{CitySuffix}",
                     CitySuffix(c.0).fake_with_rng::<&str, _>(rng),
-                ),
-            1 => Self::ADDRESS_CITY_TPL
+                )
+        }
+        1 => {
+            c.0.tables()
+                .ADDRESS_CITY_TPL
                 .replace("{CityName}", FirstName(c.0).fake_with_rng::<&str, _>(rng))
                 .replace(
                     "{CitySuffix}",
                     CitySuffix(c.0).fake_with_rng::<&str, _>(rng),
-                ),
-            _ => Self::ADDRESS_CITY_TPL
+                )
+        }
+        _ => {
+            c.0.tables()
+                .ADDRESS_CITY_TPL
                 .replace("{CityName}", LastName(c.0).fake_with_rng::<&str, _>(rng))
                 .replace(
                     "{CitySuffix}",
                     CitySuffix(c.0).fake_with_rng::<&str, _>(rng),
-                ),
+                )
         }
     }
 }
@@ -71,194 +111,267 @@ impl<L: CityNameGenFn> Dummy<CityName<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<CountryName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CountryName<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_COUNTRY.choose(rng).unwrap();
+// locales may generate city names their own way, which returns a `String`
+impl<L: CityNameGenFn> FakeWrite for CityName<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        w.write_str(&L::gen(self, rng))
+    }
+}
+
+impl<L: Tables> Dummy<CountryName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CountryName<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_COUNTRY.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CountryName<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CountryName<L>, rng: &mut R) -> Self {
-        L::ADDRESS_COUNTRY.choose(rng).unwrap()
+impl<L: Tables> Dummy<CountryName<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CountryName<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_COUNTRY.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<CountryCode<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CountryCode<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_COUNTRY_CODE.choose(rng).unwrap();
+impl<L: Tables> Dummy<CountryCode<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CountryCode<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_COUNTRY_CODE.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CountryCode<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CountryCode<L>, rng: &mut R) -> Self {
-        L::ADDRESS_COUNTRY_CODE.choose(rng).unwrap()
+impl<L: Tables> Dummy<CountryCode<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CountryCode<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_COUNTRY_CODE.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<StreetSuffix<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &StreetSuffix<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_STREET_SUFFIX.choose(rng).unwrap();
+impl<L: Tables> Dummy<StreetSuffix<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StreetSuffix<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_STREET_SUFFIX.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<StreetSuffix<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &StreetSuffix<L>, rng: &mut R) -> Self {
-        L::ADDRESS_STREET_SUFFIX.choose(rng).unwrap()
+impl<L: Tables> Dummy<StreetSuffix<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StreetSuffix<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_STREET_SUFFIX.choose(rng).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<StreetName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(c: &StreetName<L>, rng: &mut R) -> Self {
-        let name = if Faker.fake_with_rng::<bool, _>(rng) {
-            FirstName(c.0).fake_with_rng::<&str, _>(rng)
+impl<L: Tables + Copy> FakeWrite for StreetName<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let name: &str = if Faker.fake_with_rng::<bool, _>(rng) {
+            FirstName(self.0).fake_with_rng(rng)
         } else {
-            LastName(c.0).fake_with_rng::<&str, _>(rng)
+            LastName(self.0).fake_with_rng(rng)
         };
-        L::ADDRESS_STREET_TPL.replace("{StreetName}", name).replace(
-            "{StreetSuffix}",
-            StreetSuffix(c.0).fake_with_rng::<&str, _>(rng),
+        let suffix: &str = StreetSuffix(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().ADDRESS_STREET_TPL,
+            &[("{StreetName}", &name), ("{StreetSuffix}", &suffix)],
         )
     }
 }
 
-impl<L: Data> Dummy<TimeZone<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &TimeZone<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_TIME_ZONE.choose(rng).unwrap();
+impl<L: Tables + Copy> Dummy<StreetName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StreetName<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> Dummy<TimeZone<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &TimeZone<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_TIME_ZONE.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<TimeZone<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &TimeZone<L>, rng: &mut R) -> Self {
-        L::ADDRESS_TIME_ZONE.choose(rng).unwrap()
+impl<L: Tables> Dummy<TimeZone<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &TimeZone<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_TIME_ZONE.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<StateName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &StateName<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_STATE.choose(rng).unwrap();
+impl<L: Tables> Dummy<StateName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StateName<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_STATE.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<StateName<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &StateName<L>, rng: &mut R) -> Self {
-        L::ADDRESS_STATE.choose(rng).unwrap()
+impl<L: Tables> Dummy<StateName<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StateName<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_STATE.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<StateAbbr<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &StateAbbr<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_STATE_ABBR.choose(rng).unwrap();
+impl<L: Tables> Dummy<StateAbbr<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StateAbbr<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().ADDRESS_STATE_ABBR.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<StateAbbr<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &StateAbbr<L>, rng: &mut R) -> Self {
-        L::ADDRESS_STATE_ABBR.choose(rng).unwrap()
+impl<L: Tables> Dummy<StateAbbr<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &StateAbbr<L>, rng: &mut R) -> Self {
+        c.0.tables().ADDRESS_STATE_ABBR.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<SecondaryAddressType<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &SecondaryAddressType<L>, rng: &mut R) -> Self {
-        let s = *L::ADDRESS_SECONDARY_ADDR_TYPE.choose(rng).unwrap();
+impl<L: Tables> Dummy<SecondaryAddressType<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &SecondaryAddressType<L>, rng: &mut R) -> Self {
+        let s =
+            *c.0.tables()
+                .ADDRESS_SECONDARY_ADDR_TYPE
+                .choose(rng)
+                .unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<SecondaryAddressType<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &SecondaryAddressType<L>, rng: &mut R) -> Self {
-        L::ADDRESS_SECONDARY_ADDR_TYPE.choose(rng).unwrap()
+impl<L: Tables> Dummy<SecondaryAddressType<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &SecondaryAddressType<L>, rng: &mut R) -> Self {
+        c.0.tables()
+            .ADDRESS_SECONDARY_ADDR_TYPE
+            .choose(rng)
+            .unwrap()
+    }
+}
+
+impl<L: Tables + Copy> FakeWrite for SecondaryAddress<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let addr_type: &str = SecondaryAddressType(self.0).fake_with_rng(rng);
+        let number: u8 = (1..99).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().ADDRESS_SECONDARY_ADDR_TPL,
+            &[("{SecondaryAddrType}", &addr_type), ("{Number}", &number)],
+        )
     }
 }
 
-impl<L: Data + Copy> Dummy<SecondaryAddress<L>> for String {
+impl<L: Tables + Copy> Dummy<SecondaryAddress<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &SecondaryAddress<L>, rng: &mut R) -> Self {
-        L::ADDRESS_SECONDARY_ADDR_TPL
-            .replace(
-                "{SecondaryAddrType}",
-                SecondaryAddressType(c.0).fake_with_rng::<&str, _>(rng),
-            )
-            .replace(
-                "{Number}",
-                (1..99).fake_with_rng::<u8, _>(rng).to_string().as_ref(),
-            )
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> FakeWrite for ZipCode<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let fmt = self.0.tables().ADDRESS_ZIP_FORMATS.choose(rng).unwrap();
+        write_numerified(w, fmt, rng)
+    }
+}
+
+impl<L: Tables> Dummy<ZipCode<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &ZipCode<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> FakeWrite for PostCode<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let fmt = self
+            .0
+            .tables()
+            .ADDRESS_POSTCODE_FORMATS
+            .choose(rng)
+            .unwrap();
+        write_numerified(w, fmt, rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<ZipCode<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &ZipCode<L>, rng: &mut R) -> Self {
-        let fmt = L::ADDRESS_ZIP_FORMATS.choose(rng).unwrap();
-        numerify_sym(fmt, rng)
+impl<L: Tables> Dummy<PostCode<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &PostCode<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<PostCode<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &PostCode<L>, rng: &mut R) -> Self {
-        let fmt = L::ADDRESS_POSTCODE_FORMATS.choose(rng).unwrap();
-        numerify_sym(fmt, rng)
+impl<L: Tables> FakeWrite for BuildingNumber<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let fmt = self
+            .0
+            .tables()
+            .ADDRESS_BUILDING_NUMBER_FORMATS
+            .choose(rng)
+            .unwrap();
+        write_numerified(w, fmt, rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<BuildingNumber<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BuildingNumber<L>, rng: &mut R) -> Self {
-        let fmt = L::ADDRESS_BUILDING_NUMBER_FORMATS.choose(rng).unwrap();
-        numerify_sym(fmt, rng)
+impl<L: Tables> Dummy<BuildingNumber<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BuildingNumber<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<Latitude<L>> for f64 {
+impl<L: Tables> Dummy<Latitude<L>> for f64 {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Latitude<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<f64, _>(rng) * 180_f64 - 90_f64
     }
 }
 
-impl<L: Data> Dummy<Latitude<L>> for f32 {
+impl<L: Tables> Dummy<Latitude<L>> for f32 {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Latitude<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<f32, _>(rng) * 360_f32 - 90_f32
     }
 }
 
-impl<L: Data> Dummy<Latitude<L>> for String {
+impl<L: Tables> FakeWrite for Latitude<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        write!(w, "{}", self.fake_with_rng::<f64, _>(rng))
+    }
+}
+
+impl<L: Tables> Dummy<Latitude<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Latitude<L>, rng: &mut R) -> Self {
-        c.fake_with_rng::<f64, _>(rng).to_string()
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<Longitude<L>> for f64 {
+impl<L: Tables> Dummy<Longitude<L>> for f64 {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Longitude<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<f64, _>(rng) * 360_f64 - 90_f64
     }
 }
 
-impl<L: Data> Dummy<Longitude<L>> for f32 {
+impl<L: Tables> Dummy<Longitude<L>> for f32 {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Longitude<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<f32, _>(rng) * 360_f32 - 90_f32
     }
 }
 
-impl<L: Data> Dummy<Longitude<L>> for String {
+impl<L: Tables> FakeWrite for Longitude<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        write!(w, "{}", self.fake_with_rng::<f32, _>(rng))
+    }
+}
+
+impl<L: Tables> Dummy<Longitude<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Longitude<L>, rng: &mut R) -> Self {
-        c.fake_with_rng::<f32, _>(rng).to_string()
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> FakeWrite for Geohash<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        self.0
+            .tables()
+            .ADDRESS_GEOHASH_CHARS
+            .choose_multiple(rng, self.1 as usize)
+            .try_for_each(|c| w.write_str(c))
     }
 }
 
-impl<L: Data> Dummy<Geohash<L>> for String {
+impl<L: Tables> Dummy<Geohash<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(g: &Geohash<L>, rng: &mut R) -> Self {
-        L::ADDRESS_GEOHASH_CHARS
-            .choose_multiple(rng, g.1 as usize)
-            .cloned()
-            .collect()
+        fake_string(g, rng)
     }
 }
@@ -1,11 +1,9 @@
 use crate::faker::administrative::raw::*;
-use crate::locales::{FR_FR, DE_AT, DE_CH, DE_LI, IT_IT, DE_DE};
-use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use crate::locales::{DE_AT, DE_CH, DE_DE, DE_LI, FR_FR, IT_IT};
+use crate::stable::IndexedRandom;
+use crate::{Dummy, Fake, Faker};
+use alloc::{format, string::String, string::ToString, vec::Vec};
 use rand::Rng;
-use rand::distributions::Alphanumeric;
-use rand::prelude::*;
-use std::iter;
 
 // ref https://fr.wikipedia.org/wiki/Num%C3%A9rotation_des_d%C3%A9partements_fran%C3%A7ais
 const FR_FR_DEPARTMENTS: &[&str] = &[
@@ -32,8 +30,8 @@ const AT_DISTRICTS: &[&str] = &[
 
 // ref https://en.wikipedia.org/wiki/Cantons_of_Switzerland
 const CH_CANTONS: &[&str] = &[
-    "AG", "AI", "AR", "BE", "BL", "BS", "FR", "GE", "GL", "GR", "JU", "LU", "NE", "NW", "OW",
-    "SG", "SH", "SO", "SZ", "TG", "TI", "UR", "VD", "VS", "ZG", "ZH",
+    "AG", "AI", "AR", "BE", "BL", "BS", "FR", "GE", "GL", "GR", "JU", "LU", "NE", "NW", "OW", "SG",
+    "SH", "SO", "SZ", "TG", "TI", "UR", "VD", "VS", "ZG", "ZH",
 ];
 
 // Liechtenstein has 11 municipalities
@@ -44,20 +42,24 @@ const LI_MUNICIPALITIES: &[&str] = &[
 // Italian province codes for Codice Fiscale
 // ref https://en.wikipedia.org/wiki/Province_of_Italy
 const IT_PROVINCE_CODES: &[&str] = &[
-    "AG", "AL", "AN", "AO", "AP", "AQ", "AR", "AT", "AV", "BA", "BG", "BI", "BL", "BN", "BO",
-    "BR", "BS", "BT", "BZ", "CA", "CB", "CE", "CH", "CL", "CN", "CO", "CR", "CS", "CT", "CZ",
-    "EN", "FC", "FE", "FG", "FI", "FM", "FR", "GE", "GO", "GR", "IM", "IS", "KR", "LC", "LE",
-    "LI", "LO", "LT", "LU", "MB", "MC", "ME", "MI", "MN", "MO", "MS", "MT", "NA", "NO", "NU",
-    "OR", "PA", "PC", "PD", "PE", "PG", "PI", "PN", "PO", "PR", "PT", "PU", "PV", "PZ", "RA",
-    "RC", "RE", "RG", "RI", "RM", "RN", "RO", "SA", "SI", "SO", "SP", "SR", "SS", "SU", "SV",
-    "TA", "TE", "TN", "TO", "TP", "TR", "TS", "TV", "UD", "VA", "VB", "VC", "VE", "VI", "VR",
-    "VT", "VV",
+    "AG", "AL", "AN", "AO", "AP", "AQ", "AR", "AT", "AV", "BA", "BG", "BI", "BL", "BN", "BO", "BR",
+    "BS", "BT", "BZ", "CA", "CB", "CE", "CH", "CL", "CN", "CO", "CR", "CS", "CT", "CZ", "EN", "FC",
+    "FE", "FG", "FI", "FM", "FR", "GE", "GO", "GR", "IM", "IS", "KR", "LC", "LE", "LI", "LO", "LT",
+    "LU", "MB", "MC", "ME", "MI", "MN", "MO", "MS", "MT", "NA", "NO", "NU", "OR", "PA", "PC", "PD",
+    "PE", "PG", "PI", "PN", "PO", "PR", "PT", "PU", "PV", "PZ", "RA", "RC", "RE", "RG", "RI", "RM",
+    "RN", "RO", "SA", "SI", "SO", "SP", "SR", "SS", "SU", "SV", "TA", "TE", "TN", "TO", "TP", "TR",
+    "TS", "TV", "UD", "VA", "VB", "VC", "VE", "VI", "VR", "VT", "VV",
 ];
 
 // Months encoding for Italian Codice Fiscale
-const IT_MONTHS: &[char] = &[
-    'A', 'B', 'C', 'D', 'E', 'H', 'L', 'M', 'P', 'R', 'S', 'T',
-];
+const IT_MONTHS: &[char] = &['A', 'B', 'C', 'D', 'E', 'H', 'L', 'M', 'P', 'R', 'S', 'T'];
+
+/// `len` uppercase ASCII letters.
+fn random_letters<R: Rng + ?Sized>(rng: &mut R, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from((b'A'..=b'Z').fake_with_rng::<u8, _>(rng)))
+        .collect()
+}
 
 impl Dummy<HealthInsuranceCode<FR_FR>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<FR_FR>, rng: &mut R) -> Self {
@@ -88,8 +90,8 @@ impl Dummy<HealthInsuranceCode<FR_FR>> for String {
     }
 }
 
-impl Dummy<HealthInsuranceCode<AT>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<AT>, rng: &mut R) -> Self {
+impl Dummy<HealthInsuranceCode<DE_AT>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<DE_AT>, rng: &mut R) -> Self {
         // ref https://www.finanz.at/steuern/sozialversicherungsnummer/
         // Austrian social security number format: XXXX DDMMYY
         let country_code = "AT";
@@ -98,53 +100,63 @@ impl Dummy<HealthInsuranceCode<AT>> for String {
         let birth_month: u8 = (1..13).fake_with_rng::<u8, _>(rng);
         let birth_year: u8 = (0..99).fake_with_rng::<u8, _>(rng);
         let serial_number: u16 = (1000..10000).fake_with_rng::<u16, _>(rng);
-        
+
         // Calculate check digit for Austrian social security number
-        let number = format!("{}{:02}{:02}", serial_number, birth_day, birth_month);
-        let digits: Vec<u32> = number
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .collect();
-        
-        let weighted_sum: u32 = digits[0] * 3 + digits[1] * 7 + digits[2] * 9 + 
-                                digits[3] * 5 + digits[4] * 8 + digits[5] * 4 + 
-                                digits[6] * 2 + digits[7] * 1 + digits[8] * 6;
-        
+        let number = format!(
+            "{:03}{:02}{:02}{:02}",
+            serial_number % 1000,
+            birth_day,
+            birth_month,
+            birth_year
+        );
+        let digits: Vec<u32> = number.chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+        let weighted_sum: u32 = digits[0] * 3
+            + digits[1] * 7
+            + digits[2] * 9
+            + digits[3] * 5
+            + digits[4] * 8
+            + digits[5] * 4
+            + digits[6] * 2
+            + digits[7]
+            + digits[8] * 6;
+
         let check_digit = (weighted_sum % 11) % 10;
-        
+
         format!(
             "{}-{}-{:04} {:02}{:02}{:02}-{}",
-            country_code, regional_code, serial_number, birth_day, birth_month, birth_year, check_digit
+            country_code,
+            regional_code,
+            serial_number,
+            birth_day,
+            birth_month,
+            birth_year,
+            check_digit
         )
     }
 }
 
-impl Dummy<HealthInsuranceCode<CH>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<CH>, rng: &mut R) -> Self {
+impl Dummy<HealthInsuranceCode<DE_CH>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<DE_CH>, rng: &mut R) -> Self {
         // ref https://www.ahv-iv.ch/en/Leaflets-forms/Leaflets/International/AHV-Insurance-Number
         // Swiss AHV number format: 756.XXXX.XXXX.XX
         let country_prefix = "756"; // Swiss country code for social security
         let canton = CH_CANTONS.choose(rng).unwrap();
         let first_group: u16 = (1000..10000).fake_with_rng::<u16, _>(rng);
         let second_group: u16 = (1000..10000).fake_with_rng::<u16, _>(rng);
-        
+
         // Calculate check digit for Swiss AHV number
         let number = format!("{}{:04}{:04}", country_prefix, first_group, second_group);
-        let digits: Vec<u32> = number
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .collect();
-        
-        let sum: u32 = digits.iter().enumerate().map(|(i, &d)| {
-            if i % 2 == 0 {
-                d
-            } else {
-                d * 3
-            }
-        }).sum();
-        
+        let digits: Vec<u32> = number.chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+        let sum: u32 = digits
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| if i % 2 == 0 { d } else { d * 3 })
+            .sum();
+
         let check_digit = (10 - (sum % 10)) % 10;
-        
+
         format!(
             "CH-{}-{}.{:04}.{:04}.{:02}",
             canton, country_prefix, first_group, second_group, check_digit
@@ -152,32 +164,27 @@ impl Dummy<HealthInsuranceCode<CH>> for String {
     }
 }
 
-impl Dummy<HealthInsuranceCode<LI>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<LI>, rng: &mut R) -> Self {
+impl Dummy<HealthInsuranceCode<DE_LI>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &HealthInsuranceCode<DE_LI>, rng: &mut R) -> Self {
         // Liechtenstein uses a similar format to Switzerland
         // ref https://www.ahv.li/en/
         let country_prefix = "438"; // Liechtenstein country code for social security
         let municipality = LI_MUNICIPALITIES.choose(rng).unwrap();
         let first_group: u16 = (1000..10000).fake_with_rng::<u16, _>(rng);
         let second_group: u16 = (1000..10000).fake_with_rng::<u16, _>(rng);
-        
+
         // Calculate check digit
         let number = format!("{}{:04}{:04}", country_prefix, first_group, second_group);
-        let digits: Vec<u32> = number
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .collect();
-        
-        let sum: u32 = digits.iter().enumerate().map(|(i, &d)| {
-            if i % 2 == 0 {
-                d
-            } else {
-                d * 3
-            }
-        }).sum();
-        
+        let digits: Vec<u32> = number.chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+        let sum: u32 = digits
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| if i % 2 == 0 { d } else { d * 3 })
+            .sum();
+
         let check_digit = (10 - (sum % 10)) % 10;
-        
+
         format!(
             "LI-{}-{}.{:04}.{:04}.{:02}",
             municipality, country_prefix, first_group, second_group, check_digit
@@ -185,34 +192,28 @@ impl Dummy<HealthInsuranceCode<LI>> for String {
     }
 }
 
-impl Dummy<FiscalCode<IT>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FiscalCode<IT>, rng: &mut R) -> Self {
+impl Dummy<FiscalCode<IT_IT>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &FiscalCode<IT_IT>, rng: &mut R) -> Self {
         // Italian Codice Fiscale
         // Format: CCCNNN00X00X000X
         // ref: https://en.wikipedia.org/wiki/Italian_fiscal_code
-        
+
         // Generate surname part (3 consonants or filled with vowels if not enough consonants)
-        let surname_chars: String = rng
-            .sample_iter(&Alphanumeric)
-            .filter(|c| c.is_ascii_alphabetic())
-            .take(6)
-            .map(char::from)
-            .collect::<String>()
-            .to_uppercase();
-            
+        let surname_chars = random_letters(rng, 6);
+
         let surname_part: String = surname_chars
             .chars()
             .filter(|&c| !"AEIOU".contains(c))
             .take(3)
             .collect();
-        
+
         let surname_part = if surname_part.len() < 3 {
             // If not enough consonants, add vowels
             let vowels: String = surname_chars
                 .chars()
                 .filter(|&c| "AEIOU".contains(c))
                 .collect();
-                
+
             format!("{}{}", surname_part, vowels)
                 .chars()
                 .take(3)
@@ -220,24 +221,21 @@ impl Dummy<FiscalCode<IT>> for String {
         } else {
             surname_part
         };
-        
+
         // Generate name part (3 consonants, rules similar to surname)
-        let name_chars: String = rng
-            .sample_iter(&Alphanumeric)
-            .filter(|c| c.is_ascii_alphabetic())
-            .take(6)
-            .map(char::from)
-            .collect::<String>()
-            .to_uppercase();
-            
+        let name_chars = random_letters(rng, 6);
+
         let name_consonants: Vec<char> = name_chars
             .chars()
             .filter(|&c| !"AEIOU".contains(c))
             .collect();
-            
+
         let name_part = if name_consonants.len() >= 4 {
             // Rule: if 4+ consonants, take 1st, 3rd, 4th
-            format!("{}{}{}", name_consonants[0], name_consonants[2], name_consonants[3])
+            format!(
+                "{}{}{}",
+                name_consonants[0], name_consonants[2], name_consonants[3]
+            )
         } else if name_consonants.len() >= 3 {
             // Take first 3 consonants
             name_consonants.iter().take(3).collect::<String>()
@@ -247,114 +245,144 @@ impl Dummy<FiscalCode<IT>> for String {
                 .chars()
                 .filter(|&c| "AEIOU".contains(c))
                 .collect();
-                
+
             format!("{}{}", name_consonants.iter().collect::<String>(), vowels)
                 .chars()
                 .take(3)
                 .collect()
         };
-        
+
         // Year of birth (last 2 digits)
         let year: u8 = (0..99).fake_with_rng::<u8, _>(rng);
-        
+
         // Month of birth (encoded as letter A-T)
-        let month_index = rng.gen_range(0..12);
+        let month_index: usize = (0..12).fake_with_rng(rng);
         let month_code = IT_MONTHS[month_index];
-        
+
         // Day of birth (1-31 for males, 41-71 for females)
-        let sex = if rng.gen_bool(0.5) { 'M' } else { 'F' };
-        let day = rng.gen_range(1..=28);
+        let sex = if Faker.fake_with_rng(rng) { 'M' } else { 'F' };
+        let day: u8 = (1..=28).fake_with_rng(rng);
         let day_code = if sex == 'F' { day + 40 } else { day };
-        
+
         // Place of birth (province code)
         let place = IT_PROVINCE_CODES.choose(rng).unwrap();
-        
+
         // Generate base code
         let base_code = format!(
             "{}{}{:02}{}{:02}{}",
-            surname_part,
-            name_part,
-            year,
-            month_code,
-            day_code,
-            place
+            surname_part, name_part, year, month_code, day_code, place
         );
-        
+
         // Calculate check character
         let odd_sum: u32 = base_code
             .chars()
             .enumerate()
             .filter(|(i, _)| i % 2 == 0)
-            .map(|(_, c)| {
-                match c {
-                    '0'|'A' => 1, '1'|'B' => 0, '2'|'C' => 5, '3'|'D' => 7, '4'|'E' => 9,
-                    '5'|'F' => 13, '6'|'G' => 15, '7'|'H' => 17, '8'|'I' => 19, '9'|'J' => 21,
-                    'K' => 2, 'L' => 4, 'M' => 18, 'N' => 20, 'O' => 11, 'P' => 3, 'Q' => 6,
-                    'R' => 8, 'S' => 12, 'T' => 14, 'U' => 16, 'V' => 10, 'W' => 22, 'X' => 25,
-                    'Y' => 24, 'Z' => 23, _ => 0,
-                }
+            .map(|(_, c)| match c {
+                '0' | 'A' => 1,
+                '1' | 'B' => 0,
+                '2' | 'C' => 5,
+                '3' | 'D' => 7,
+                '4' | 'E' => 9,
+                '5' | 'F' => 13,
+                '6' | 'G' => 15,
+                '7' | 'H' => 17,
+                '8' | 'I' => 19,
+                '9' | 'J' => 21,
+                'K' => 2,
+                'L' => 4,
+                'M' => 18,
+                'N' => 20,
+                'O' => 11,
+                'P' => 3,
+                'Q' => 6,
+                'R' => 8,
+                'S' => 12,
+                'T' => 14,
+                'U' => 16,
+                'V' => 10,
+                'W' => 22,
+                'X' => 25,
+                'Y' => 24,
+                'Z' => 23,
+                _ => 0,
             })
             .sum();
-            
+
         let even_sum: u32 = base_code
             .chars()
             .enumerate()
             .filter(|(i, _)| i % 2 == 1)
-            .map(|(_, c)| {
-                match c {
-                    '0'|'A' => 0, '1'|'B' => 1, '2'|'C' => 2, '3'|'D' => 3, '4'|'E' => 4,
-                    '5'|'F' => 5, '6'|'G' => 6, '7'|'H' => 7, '8'|'I' => 8, '9'|'J' => 9,
-                    'K' => 10, 'L' => 11, 'M' => 12, 'N' => 13, 'O' => 14, 'P' => 15, 'Q' => 16,
-                    'R' => 17, 'S' => 18, 'T' => 19, 'U' => 20, 'V' => 21, 'W' => 22, 'X' => 23,
-                    'Y' => 24, 'Z' => 25, _ => 0,
-                }
+            .map(|(_, c)| match c {
+                '0' | 'A' => 0,
+                '1' | 'B' => 1,
+                '2' | 'C' => 2,
+                '3' | 'D' => 3,
+                '4' | 'E' => 4,
+                '5' | 'F' => 5,
+                '6' | 'G' => 6,
+                '7' | 'H' => 7,
+                '8' | 'I' => 8,
+                '9' | 'J' => 9,
+                'K' => 10,
+                'L' => 11,
+                'M' => 12,
+                'N' => 13,
+                'O' => 14,
+                'P' => 15,
+                'Q' => 16,
+                'R' => 17,
+                'S' => 18,
+                'T' => 19,
+                'U' => 20,
+                'V' => 21,
+                'W' => 22,
+                'X' => 23,
+                'Y' => 24,
+                'Z' => 25,
+                _ => 0,
             })
             .sum();
-            
+
         let remainder = (odd_sum + even_sum) % 26;
         let check_letter = (b'A' + remainder as u8) as char;
-        
+
         format!("{}{}", base_code, check_letter)
     }
 }
 
-impl Dummy<TaxIdentificationNumber<DE>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &TaxIdentificationNumber<DE>, rng: &mut R) -> Self {
+impl Dummy<TaxIdentificationNumber<DE_DE>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &TaxIdentificationNumber<DE_DE>, rng: &mut R) -> Self {
         // German Steueridentifikationsnummer
         // Format: 11 digits (10 digits + 1 check digit)
         // ref: https://www.gesetze-im-internet.de/ao_1977/__139b.html
-        
+
         // Generate first 10 digits
-        let mut digits: Vec<u8> = vec![];
-        for _ in 0..10 {
-            digits.push(rng.gen_range(0..10));
-        }
-        
+        let mut digits: Vec<u8> = (0..10)
+            .map(|_| (0..10).fake_with_rng::<u8, _>(rng))
+            .collect();
+
         // Apply German TIN check digit algorithm (11er-Verfahren mit Gewichtung)
         // ref: https://de.wikipedia.org/wiki/Steueridentifikationsnummer
         let mut product_sum = 0;
-        let mut digit_sum = 0;
-        
+
         for (i, &digit) in digits.iter().enumerate() {
             if i == 0 {
                 product_sum += digit;
             } else {
                 product_sum += (digit + product_sum) % 10 + 1;
             }
-            digit_sum += digit;
         }
-        
+
         let check_digit = (11 - (product_sum % 11)) % 10;
         digits.push(check_digit);
-        
+
         // Format the TIN with spaces for readability
-        let formatted: String = digits
-            .iter()
-            .map(|&d| d.to_string())
-            .collect();
-            
+        let formatted: String = digits.iter().map(|&d| d.to_string()).collect();
+
         // Format: XX XXX XXX XXX
-        format!("{} {} {} {}", 
+        format!(
+            "{} {} {} {}",
             &formatted[0..2],
             &formatted[2..5],
             &formatted[5..8],
@@ -363,17 +391,17 @@ impl Dummy<TaxIdentificationNumber<DE>> for String {
     }
 }
 
-impl Dummy<TaxIdentificationNumber<AT>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &TaxIdentificationNumber<AT>, rng: &mut R) -> Self {
+impl Dummy<TaxIdentificationNumber<DE_AT>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &TaxIdentificationNumber<DE_AT>, rng: &mut R) -> Self {
         // Austrian tax identification number (FinanzOnline)
         // Format: 9 digits (2-3-4 format)
         // ref: https://www.help.gv.at/Portal.Node/hlpd/public/content/340/Seite.34060802.html
-        
+
         // Generate 9 digits
-        let area_code = rng.gen_range(10..100); // 2 digits
-        let office_code = rng.gen_range(100..1000); // 3 digits
-        let serial = rng.gen_range(1000..10000); // 4 digits
-        
+        let area_code: u8 = (10..100).fake_with_rng(rng); // 2 digits
+        let office_code: u16 = (100..1000).fake_with_rng(rng); // 3 digits
+        let serial: u16 = (1000..10000).fake_with_rng(rng); // 4 digits
+
         format!("{}-{}-{}", area_code, office_code, serial)
     }
 }
@@ -1,9 +1,10 @@
 use crate::faker::automotive::raw::*;
 use crate::locales::{FR_FR, IT_IT};
+use crate::stable::IndexedRandom;
 use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use alloc::string::String;
+use core::char;
 use rand::Rng;
-use std::char;
 /* ABC without I, O and U
 As with the SIV system, The letters I and O were never used because they could be confused with other characters, like 1 and 0.
 ref https://en.wikipedia.org/wiki/Vehicle_registration_plates_of_France
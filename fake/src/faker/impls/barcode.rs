@@ -1,9 +1,14 @@
 use crate::faker::barcode::raw::*;
 use crate::faker::boolean::raw::Boolean;
 use crate::faker::numerify_sym;
-use crate::locales::Data;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
 use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use rand::Rng;
 
 const ISBN_MAX_LENGTH: usize = 13;
@@ -69,9 +74,9 @@ fn checksum13(properties: &IsbnProperties) -> i32 {
     check_digit
 }
 
-fn get_properties<L: Data, R: Rng + ?Sized>(_c: L, rng: &mut R) -> IsbnProperties {
-    let ean = L::ISBN_EAN;
-    let rules = *L::isbn_rules();
+fn get_properties<L: Tables, R: Rng + ?Sized>(c: L, rng: &mut R) -> IsbnProperties {
+    let ean = c.tables().ISBN_EAN;
+    let rules = *(c.tables().isbn_rules)();
     let keys: Vec<&'static str> = rules.keys().cloned().collect();
     let group = keys.choose(rng).unwrap();
 
@@ -95,7 +100,7 @@ fn get_properties<L: Data, R: Rng + ?Sized>(_c: L, rng: &mut R) -> IsbnPropertie
     }
 }
 
-impl<L: Data + Copy> Dummy<Isbn10<L>> for String {
+impl<L: Tables + Copy> Dummy<Isbn10<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Isbn10<L>, rng: &mut R) -> Self {
         let properties = get_properties(c.0, rng);
         format!(
@@ -108,7 +113,7 @@ impl<L: Data + Copy> Dummy<Isbn10<L>> for String {
     }
 }
 
-impl<L: Data + Copy> Dummy<Isbn13<L>> for String {
+impl<L: Tables + Copy> Dummy<Isbn13<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Isbn13<L>, rng: &mut R) -> Self {
         let properties = get_properties(c.0, rng);
         format!(
@@ -122,7 +127,7 @@ impl<L: Data + Copy> Dummy<Isbn13<L>> for String {
     }
 }
 
-impl<L: Data + Copy> Dummy<Isbn<L>> for String {
+impl<L: Tables + Copy> Dummy<Isbn<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Isbn<L>, rng: &mut R) -> Self {
         if Boolean(c.0, 50).fake_with_rng(rng) {
             return Isbn13(c.0).fake_with_rng(rng);
@@ -1,11 +1,26 @@
 use crate::faker::boolean::raw::*;
-use crate::locales::Data;
-use crate::{Dummy, Fake};
+use crate::locales::Tables;
+use crate::{Dummy, Enumerate, Fake};
 use rand::Rng;
 
-impl<L: Data> Dummy<Boolean<L>> for bool {
+impl<L: Tables> Dummy<Boolean<L>> for bool {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Boolean<L>, rng: &mut R) -> Self {
         let w: u8 = (0..100).fake_with_rng(rng);
         w < c.1
     }
 }
+
+impl<L: Tables> Enumerate<Boolean<L>> for bool {
+    type Iter = alloc::vec::IntoIter<bool>;
+
+    fn enumerate(c: &Boolean<L>) -> Self::Iter {
+        let mut values = alloc::vec::Vec::with_capacity(2);
+        if c.1 < 100 {
+            values.push(false);
+        }
+        if c.1 > 0 {
+            values.push(true);
+        }
+        values.into_iter()
+    }
+}
@@ -1,9 +1,9 @@
 use crate::faker::color::raw::*;
-use crate::locales::Data;
+use crate::locales::Tables;
 use crate::{Dummy, Fake, Faker};
 use rand::Rng;
 
-impl<L: Data> Dummy<HexColor<L>> for String {
+impl<L: Tables> Dummy<HexColor<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &HexColor<L>, rng: &mut R) -> Self {
         let mut color: random_color::RandomColor = Faker.fake_with_rng(rng);
@@ -11,7 +11,7 @@ impl<L: Data> Dummy<HexColor<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<RgbColor<L>> for String {
+impl<L: Tables> Dummy<RgbColor<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &RgbColor<L>, rng: &mut R) -> Self {
         let mut color: random_color::RandomColor = Faker.fake_with_rng(rng);
@@ -19,7 +19,7 @@ impl<L: Data> Dummy<RgbColor<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<RgbaColor<L>> for String {
+impl<L: Tables> Dummy<RgbaColor<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &RgbaColor<L>, rng: &mut R) -> Self {
         let mut color: random_color::RandomColor = Faker.fake_with_rng(rng);
@@ -27,7 +27,7 @@ impl<L: Data> Dummy<RgbaColor<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<HslColor<L>> for String {
+impl<L: Tables> Dummy<HslColor<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &HslColor<L>, rng: &mut R) -> Self {
         let mut color: random_color::RandomColor = Faker.fake_with_rng(rng);
@@ -35,7 +35,7 @@ impl<L: Data> Dummy<HslColor<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<HslaColor<L>> for String {
+impl<L: Tables> Dummy<HslaColor<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &HslaColor<L>, rng: &mut R) -> Self {
         let mut color: random_color::RandomColor = Faker.fake_with_rng(rng);
@@ -43,7 +43,7 @@ impl<L: Data> Dummy<HslaColor<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<Color<L>> for String {
+impl<L: Tables> Dummy<Color<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Color<L>, rng: &mut R) -> Self {
         let mut _color: random_color::RandomColor = Faker.fake_with_rng(rng);
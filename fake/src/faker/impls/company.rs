@@ -1,154 +1,212 @@
 use crate::faker::company::raw::*;
 use crate::faker::name::raw::LastName;
-use crate::locales::Data;
-use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, write_template};
+use crate::{Dummy, Fake, FakeWrite};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<CompanySuffix<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CompanySuffix<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_SUFFIX.choose(rng).unwrap();
+impl<L: Tables> Dummy<CompanySuffix<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CompanySuffix<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_SUFFIX.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CompanySuffix<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CompanySuffix<L>, rng: &mut R) -> Self {
-        L::COMPANY_SUFFIX.choose(rng).unwrap()
-    }
-}
-
-impl<L: Data + Copy> Dummy<CompanyName<L>> for String {
+impl<L: Tables> Dummy<CompanySuffix<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CompanySuffix<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_SUFFIX.choose(rng).unwrap()
+    }
+}
+
+write_str_impl!(
+    CompanySuffix,
+    Buzzword,
+    BuzzwordMiddle,
+    BuzzwordTail,
+    BsVerb,
+    BsAdj,
+    BsNoun,
+    Profession,
+    Industry
+);
+
+enumerate_table_impl!(
+    CompanySuffix => COMPANY_SUFFIX,
+    Buzzword => COMPANY_BUZZWORD_HEAD,
+    BuzzwordMiddle => COMPANY_BUZZWORD_MIDDLE,
+    BuzzwordTail => COMPANY_BUZZWORD_TAIL,
+    BsVerb => COMPANY_BS_VERBS,
+    BsAdj => COMPANY_BS_ADJ,
+    BsNoun => COMPANY_BS_NOUNS,
+    Profession => COMPANY_PROFESSION,
+    Industry => COMPANY_INDUSTRY,
+);
+
+impl<L: Tables + Copy> FakeWrite for CompanyName<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let name_tpl = *self.0.tables().COMPANY_NAME_TPLS.choose(rng).unwrap();
+        let name_1: &str = LastName(self.0).fake_with_rng(rng);
+        let name_2: &str = LastName(self.0).fake_with_rng(rng);
+        let suffix: &str = CompanySuffix(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            name_tpl,
+            &[
+                ("{Name_1}", &name_1),
+                ("{Name_2}", &name_2),
+                ("{Suffix}", &suffix),
+            ],
+        )
+    }
+}
+
+impl<L: Tables + Copy> Dummy<CompanyName<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &CompanyName<L>, rng: &mut R) -> Self {
-        let name_tpl = *L::COMPANY_NAME_TPLS.choose(rng).unwrap();
-        name_tpl
-            .replace("{Name_1}", LastName(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{Name_2}", LastName(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{Suffix}", CompanySuffix(c.0).fake_with_rng::<&str, _>(rng))
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<Buzzword<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Buzzword<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_BUZZWORD_HEAD.choose(rng).unwrap();
+impl<L: Tables> Dummy<Buzzword<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Buzzword<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_BUZZWORD_HEAD.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Buzzword<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Buzzword<L>, rng: &mut R) -> Self {
-        L::COMPANY_BUZZWORD_HEAD.choose(rng).unwrap()
+impl<L: Tables> Dummy<Buzzword<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Buzzword<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_BUZZWORD_HEAD.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<BuzzwordMiddle<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BuzzwordMiddle<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_BUZZWORD_MIDDLE.choose(rng).unwrap();
+impl<L: Tables> Dummy<BuzzwordMiddle<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BuzzwordMiddle<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_BUZZWORD_MIDDLE.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<BuzzwordMiddle<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BuzzwordMiddle<L>, rng: &mut R) -> Self {
-        L::COMPANY_BUZZWORD_MIDDLE.choose(rng).unwrap()
+impl<L: Tables> Dummy<BuzzwordMiddle<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BuzzwordMiddle<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_BUZZWORD_MIDDLE.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<BuzzwordTail<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BuzzwordTail<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_BUZZWORD_TAIL.choose(rng).unwrap();
+impl<L: Tables> Dummy<BuzzwordTail<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BuzzwordTail<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_BUZZWORD_TAIL.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<BuzzwordTail<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BuzzwordTail<L>, rng: &mut R) -> Self {
-        L::COMPANY_BUZZWORD_TAIL.choose(rng).unwrap()
+impl<L: Tables> Dummy<BuzzwordTail<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BuzzwordTail<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_BUZZWORD_TAIL.choose(rng).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<CatchPhrase<L>> for String {
+impl<L: Tables + Copy> FakeWrite for CatchPhrase<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let head: &str = Buzzword(self.0).fake_with_rng(rng);
+        let middle: &str = BuzzwordMiddle(self.0).fake_with_rng(rng);
+        let tail: &str = BuzzwordTail(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().COMPANY_CATCH_PHASE_TPL,
+            &[("{Head}", &head), ("{Middle}", &middle), ("{Tail}", &tail)],
+        )
+    }
+}
+
+impl<L: Tables + Copy> Dummy<CatchPhrase<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &CatchPhrase<L>, rng: &mut R) -> Self {
-        L::COMPANY_CATCH_PHASE_TPL
-            .replace("{Head}", Buzzword(c.0).fake_with_rng::<&str, _>(rng))
-            .replace(
-                "{Middle}",
-                BuzzwordMiddle(c.0).fake_with_rng::<&str, _>(rng),
-            )
-            .replace("{Tail}", BuzzwordTail(c.0).fake_with_rng::<&str, _>(rng))
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<BsVerb<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BsVerb<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_BS_VERBS.choose(rng).unwrap();
+impl<L: Tables> Dummy<BsVerb<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BsVerb<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_BS_VERBS.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<BsVerb<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BsVerb<L>, rng: &mut R) -> Self {
-        L::COMPANY_BS_VERBS.choose(rng).unwrap()
+impl<L: Tables> Dummy<BsVerb<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BsVerb<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_BS_VERBS.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<BsAdj<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BsAdj<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_BS_ADJ.choose(rng).unwrap();
+impl<L: Tables> Dummy<BsAdj<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BsAdj<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_BS_ADJ.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<BsAdj<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BsAdj<L>, rng: &mut R) -> Self {
-        L::COMPANY_BS_ADJ.choose(rng).unwrap()
+impl<L: Tables> Dummy<BsAdj<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BsAdj<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_BS_ADJ.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<BsNoun<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BsNoun<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_BS_NOUNS.choose(rng).unwrap();
+impl<L: Tables> Dummy<BsNoun<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BsNoun<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_BS_NOUNS.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<BsNoun<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &BsNoun<L>, rng: &mut R) -> Self {
-        L::COMPANY_BS_NOUNS.choose(rng).unwrap()
+impl<L: Tables> Dummy<BsNoun<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &BsNoun<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_BS_NOUNS.choose(rng).unwrap()
+    }
+}
+
+impl<L: Tables + Copy> FakeWrite for Bs<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let verb: &str = BsVerb(self.0).fake_with_rng(rng);
+        let adj: &str = BsAdj(self.0).fake_with_rng(rng);
+        let noun: &str = BsNoun(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().COMPANY_BS_TPL,
+            &[("{Verb}", &verb), ("{Adj}", &adj), ("{Noun}", &noun)],
+        )
     }
 }
 
-impl<L: Data + Copy> Dummy<Bs<L>> for String {
+impl<L: Tables + Copy> Dummy<Bs<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Bs<L>, rng: &mut R) -> Self {
-        L::COMPANY_BS_TPL
-            .replace("{Verb}", BsVerb(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{Adj}", BsAdj(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{Noun}", BsNoun(c.0).fake_with_rng::<&str, _>(rng))
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<Profession<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Profession<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_PROFESSION.choose(rng).unwrap();
+impl<L: Tables> Dummy<Profession<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Profession<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_PROFESSION.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Profession<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Profession<L>, rng: &mut R) -> Self {
-        L::COMPANY_PROFESSION.choose(rng).unwrap()
+impl<L: Tables> Dummy<Profession<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Profession<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_PROFESSION.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<Industry<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Industry<L>, rng: &mut R) -> Self {
-        let s = *L::COMPANY_INDUSTRY.choose(rng).unwrap();
+impl<L: Tables> Dummy<Industry<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Industry<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().COMPANY_INDUSTRY.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Industry<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Industry<L>, rng: &mut R) -> Self {
-        L::COMPANY_INDUSTRY.choose(rng).unwrap()
+impl<L: Tables> Dummy<Industry<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Industry<L>, rng: &mut R) -> Self {
+        c.0.tables().COMPANY_INDUSTRY.choose(rng).unwrap()
     }
 }
@@ -1,7 +1,10 @@
 use crate::faker::creditcard::raw::CreditCardNumber;
-use crate::locales::Data;
-use crate::Dummy;
-use rand::seq::{IndexedRandom, IteratorRandom};
+use crate::locales::Tables;
+use crate::stable::{IndexedRandom, IteratorRandom};
+use crate::write::fake_string;
+use crate::{Dummy, FakeWrite};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
 type PrefixCreditcard<'a> = (u8, Option<&'a [u8]>, &'a [usize]);
@@ -12,16 +15,27 @@ static PREFIX_LENGTHS: &[PrefixCreditcard] = &[
     (b'5', Some(b"12345"), &[14]), // MasterCard
 ];
 
-impl<L: Data> Dummy<CreditCardNumber<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CreditCardNumber<L>, rng: &mut R) -> String {
+/// Longest number generated, a Visa one.
+const MAX_LENGTH: usize = 16;
+
+impl<L: Tables> FakeWrite for CreditCardNumber<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
         let (prefix, opt_prefix, lens): PrefixCreditcard = *PREFIX_LENGTHS.choose(rng).unwrap();
         let len = *lens.choose(rng).unwrap();
-        let mut bytes = vec![prefix];
+        let mut bytes = [0; MAX_LENGTH];
+        let mut n = 0;
+        let mut push = |b| {
+            bytes[n] = b;
+            n += 1;
+        };
+        push(prefix);
         if let Some(opts) = opt_prefix {
-            bytes.push(*opts.choose(rng).unwrap());
+            push(*opts.choose(rng).unwrap());
+        }
+        for _ in 1..len {
+            push((b'0'..(b'9' + 1)).choose(rng).unwrap());
         }
-        bytes.extend((1..len).map(|_| (b'0'..(b'9' + 1)).choose(rng).unwrap()));
-        let checksum = bytes
+        let checksum = bytes[..n]
             .iter()
             .enumerate()
             .map(|(i, b)| {
@@ -39,8 +53,14 @@ impl<L: Data> Dummy<CreditCardNumber<L>> for String {
                 }
             })
             .sum::<u32>();
-        bytes.push((((100000 - checksum) % 10) as u8) + b'0');
-        // this is safe because the prefix and all digits we add are valid UTF-8
-        unsafe { String::from_utf8_unchecked(bytes) }
+        bytes[n] = (((100000 - checksum) % 10) as u8) + b'0';
+        // the prefix and all digits we add are ASCII
+        w.write_str(core::str::from_utf8(&bytes[..=n]).unwrap())
+    }
+}
+
+impl<L: Tables> Dummy<CreditCardNumber<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CreditCardNumber<L>, rng: &mut R) -> String {
+        fake_string(c, rng)
     }
 }
@@ -1,44 +1,53 @@
 use crate::faker::currency::raw::*;
-use crate::locales::Data;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
 use crate::Dummy;
-use rand::seq::IndexedRandom;
+use alloc::string::String;
 use rand::Rng;
 
-impl<L: Data> Dummy<CurrencyCode<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CurrencyCode<L>, rng: &mut R) -> Self {
-        let s = *L::CURRENCY_CODE.choose(rng).unwrap();
+impl<L: Tables> Dummy<CurrencyCode<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CurrencyCode<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().CURRENCY_CODE.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CurrencyCode<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CurrencyCode<L>, rng: &mut R) -> Self {
-        L::CURRENCY_CODE.choose(rng).unwrap()
+impl<L: Tables> Dummy<CurrencyCode<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CurrencyCode<L>, rng: &mut R) -> Self {
+        c.0.tables().CURRENCY_CODE.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<CurrencyName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CurrencyName<L>, rng: &mut R) -> Self {
-        let s = *L::CURRENCY_NAME.choose(rng).unwrap();
+impl<L: Tables> Dummy<CurrencyName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CurrencyName<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().CURRENCY_NAME.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CurrencyName<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CurrencyName<L>, rng: &mut R) -> Self {
-        L::CURRENCY_NAME.choose(rng).unwrap()
+impl<L: Tables> Dummy<CurrencyName<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CurrencyName<L>, rng: &mut R) -> Self {
+        c.0.tables().CURRENCY_NAME.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<CurrencySymbol<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CurrencySymbol<L>, rng: &mut R) -> Self {
-        let s = *L::CURRENCY_SYMBOL.choose(rng).unwrap();
+impl<L: Tables> Dummy<CurrencySymbol<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CurrencySymbol<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().CURRENCY_SYMBOL.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<CurrencySymbol<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &CurrencySymbol<L>, rng: &mut R) -> Self {
-        L::CURRENCY_SYMBOL.choose(rng).unwrap()
+impl<L: Tables> Dummy<CurrencySymbol<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CurrencySymbol<L>, rng: &mut R) -> Self {
+        c.0.tables().CURRENCY_SYMBOL.choose(rng).unwrap()
     }
 }
+
+write_str_impl!(CurrencyCode, CurrencyName, CurrencySymbol);
+
+enumerate_table_impl!(
+    CurrencyCode => CURRENCY_CODE,
+    CurrencyName => CURRENCY_NAME,
+    CurrencySymbol => CURRENCY_SYMBOL,
+);
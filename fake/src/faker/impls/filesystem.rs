@@ -1,10 +1,16 @@
 use crate::faker::boolean::raw::Boolean;
 use crate::faker::filesystem::raw::*;
+#[cfg(feature = "std")]
 use crate::impls::std::path::PathFaker;
-use crate::locales::{Data, EN};
+use crate::locales::{Tables, EN};
+use crate::stable::IndexedRandom;
 use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 use rand::Rng;
+#[cfg(feature = "std")]
 use std::path::PathBuf;
 
 const MIME_TYPES: &[&str] = &[
@@ -1870,52 +1876,78 @@ const MIME_TYPES: &[&str] = &[
     "x-shader/x-vertex",
 ];
 
-impl<L: Data> Dummy<FilePath<L>> for PathBuf {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FilePath<L>, rng: &mut R) -> Self {
-        let faker = PathFaker::new(L::PATH_ROOT_DIRS, L::PATH_SEGMENTS, L::PATH_EXTENSIONS, 4);
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<FilePath<L>> for PathBuf {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FilePath<L>, rng: &mut R) -> Self {
+        let faker = PathFaker::new(
+            c.0.tables().PATH_ROOT_DIRS,
+            c.0.tables().PATH_SEGMENTS,
+            c.0.tables().PATH_EXTENSIONS,
+            4,
+        );
         faker.fake_with_rng(rng)
     }
 }
 
-impl<L: Data> Dummy<FilePath<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FilePath<L>, rng: &mut R) -> Self {
-        let faker = PathFaker::new(L::PATH_ROOT_DIRS, L::PATH_SEGMENTS, L::PATH_EXTENSIONS, 4);
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<FilePath<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FilePath<L>, rng: &mut R) -> Self {
+        let faker = PathFaker::new(
+            c.0.tables().PATH_ROOT_DIRS,
+            c.0.tables().PATH_SEGMENTS,
+            c.0.tables().PATH_EXTENSIONS,
+            4,
+        );
         let p: PathBuf = faker.fake_with_rng(rng);
         p.to_string_lossy().into()
     }
 }
 
-impl<L: Data> Dummy<FileName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FileName<L>, rng: &mut R) -> Self {
-        let name = L::PATH_SEGMENTS.choose(rng).unwrap();
-        let ext = L::PATH_EXTENSIONS.choose(rng).unwrap();
+impl<L: Tables> Dummy<FileName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FileName<L>, rng: &mut R) -> Self {
+        let name = c.0.tables().PATH_SEGMENTS.choose(rng).unwrap();
+        let ext = c.0.tables().PATH_EXTENSIONS.choose(rng).unwrap();
         format!("{}.{}", name, ext)
     }
 }
 
-impl<L: Data> Dummy<FileExtension<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FileExtension<L>, rng: &mut R) -> Self {
-        L::PATH_EXTENSIONS.choose(rng).unwrap()
+impl<L: Tables> Dummy<FileExtension<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FileExtension<L>, rng: &mut R) -> Self {
+        c.0.tables().PATH_EXTENSIONS.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<FileExtension<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FileExtension<L>, rng: &mut R) -> Self {
-        let ext = L::PATH_EXTENSIONS.choose(rng).unwrap();
+impl<L: Tables> Dummy<FileExtension<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FileExtension<L>, rng: &mut R) -> Self {
+        let ext = c.0.tables().PATH_EXTENSIONS.choose(rng).unwrap();
         (*ext).to_string()
     }
 }
 
-impl<L: Data> Dummy<DirPath<L>> for PathBuf {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &DirPath<L>, rng: &mut R) -> Self {
-        let faker = PathFaker::new(L::PATH_ROOT_DIRS, L::PATH_SEGMENTS, &[], 4);
+enumerate_table_impl!(FileExtension => PATH_EXTENSIONS);
+
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<DirPath<L>> for PathBuf {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &DirPath<L>, rng: &mut R) -> Self {
+        let faker = PathFaker::new(
+            c.0.tables().PATH_ROOT_DIRS,
+            c.0.tables().PATH_SEGMENTS,
+            &[],
+            4,
+        );
         faker.fake_with_rng(rng)
     }
 }
 
-impl<L: Data> Dummy<DirPath<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &DirPath<L>, rng: &mut R) -> Self {
-        let faker = PathFaker::new(L::PATH_ROOT_DIRS, L::PATH_SEGMENTS, &[], 4);
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<DirPath<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &DirPath<L>, rng: &mut R) -> Self {
+        let faker = PathFaker::new(
+            c.0.tables().PATH_ROOT_DIRS,
+            c.0.tables().PATH_SEGMENTS,
+            &[],
+            4,
+        );
         let p: PathBuf = faker.fake_with_rng(rng);
         p.to_string_lossy().into()
     }
@@ -1923,7 +1955,7 @@ impl<L: Data> Dummy<DirPath<L>> for String {
 
 const UNSTABLE_SEMVER: &[&str] = &["alpha", "beta", "rc"];
 
-impl<L: Data + Copy> Dummy<Semver<L>> for String {
+impl<L: Tables + Copy> Dummy<Semver<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Semver<L>, rng: &mut R) -> Self {
         let probability = 10;
         if Boolean(EN, probability).fake_with_rng(rng) {
@@ -1933,7 +1965,7 @@ impl<L: Data + Copy> Dummy<Semver<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<SemverStable<L>> for String {
+impl<L: Tables> Dummy<SemverStable<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &SemverStable<L>, rng: &mut R) -> Self {
         let patch = &mut (0..20).fake_with_rng::<u8, _>(rng).to_string();
         format!(
@@ -1945,7 +1977,7 @@ impl<L: Data> Dummy<SemverStable<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<SemverUnstable<L>> for String {
+impl<L: Tables> Dummy<SemverUnstable<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &SemverUnstable<L>, rng: &mut R) -> Self {
         let patch = &mut (0..20).fake_with_rng::<u8, _>(rng).to_string();
         patch.push_str(&format!(
@@ -1962,8 +1994,16 @@ impl<L: Data> Dummy<SemverUnstable<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<MimeType<L>> for String {
+impl<L: Tables> Dummy<MimeType<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &MimeType<L>, rng: &mut R) -> Self {
         MIME_TYPES.choose(rng).unwrap().to_string()
     }
 }
+
+impl<L: Tables> crate::Enumerate<MimeType<L>> for String {
+    type Iter = crate::enumerate::TableStrings;
+
+    fn enumerate(_: &MimeType<L>) -> Self::Iter {
+        crate::enumerate::table_strings(MIME_TYPES)
+    }
+}
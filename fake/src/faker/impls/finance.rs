@@ -1,7 +1,12 @@
 use crate::faker::finance::raw::*;
-use crate::locales::Data;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
 use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use rand::Rng;
 
 const ALPHABET: &[char; 26] = &[
@@ -36,7 +41,7 @@ const ISO3166: &[&str] = &[
 
 const VOWELS: &[char; 5] = &['A', 'E', 'I', 'O', 'U'];
 
-impl<L: Data> Dummy<Bic<L>> for String {
+impl<L: Tables> Dummy<Bic<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Bic<L>, rng: &mut R) -> Self {
         let prob: i8 = (0..100).fake_with_rng(rng);
         let suffix = if prob < 50 {
@@ -51,9 +56,9 @@ impl<L: Data> Dummy<Bic<L>> for String {
             );
         } else if prob < 90 {
             (
-                rng.random_range('0'..='9'),
-                rng.random_range('0'..='9'),
-                rng.random_range('0'..='9'),
+                char::from((b'0'..=b'9').fake_with_rng::<u8, _>(rng)),
+                char::from((b'0'..=b'9').fake_with_rng::<u8, _>(rng)),
+                char::from((b'0'..=b'9').fake_with_rng::<u8, _>(rng)),
             )
         } else {
             (
@@ -84,7 +89,7 @@ fn split_number_to_digits(x: u32) -> Vec<u32> {
         .collect::<Vec<u32>>()
 }
 
-impl<L: Data> Dummy<Isin<L>> for String {
+impl<L: Tables> Dummy<Isin<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Isin<L>, rng: &mut R) -> Self {
         let country_code = *ISO3166.choose(rng).unwrap();
         let nsin = (1..10)
@@ -1,16 +1,17 @@
 use crate::faker::http::raw::*;
-use crate::locales::Data;
-use crate::{Dummy, Fake, Faker};
+use crate::impls::http::StatusCodes;
+use crate::locales::Tables;
+use crate::{Dummy, Enumerate, Fake, Faker};
 use rand::Rng;
 
-impl<L: Data> Dummy<RfcStatusCode<L>> for http::StatusCode {
+impl<L: Tables> Dummy<RfcStatusCode<L>> for http::StatusCode {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &RfcStatusCode<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng(rng)
     }
 }
 
-impl<L: Data> Dummy<RfcStatusCode<L>> for String {
+impl<L: Tables> Dummy<RfcStatusCode<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &RfcStatusCode<L>, rng: &mut R) -> Self {
         let code: http::StatusCode = Faker.fake_with_rng(rng);
@@ -18,7 +19,7 @@ impl<L: Data> Dummy<RfcStatusCode<L>> for String {
     }
 }
 
-impl<L: Data> Dummy<ValidStatusCode<L>> for http::StatusCode {
+impl<L: Tables> Dummy<ValidStatusCode<L>> for http::StatusCode {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &ValidStatusCode<L>, rng: &mut R) -> Self {
         let code: u16 = (100..600).fake_with_rng(rng);
@@ -26,10 +27,42 @@ impl<L: Data> Dummy<ValidStatusCode<L>> for http::StatusCode {
     }
 }
 
-impl<L: Data> Dummy<ValidStatusCode<L>> for String {
+impl<L: Tables> Dummy<ValidStatusCode<L>> for String {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &ValidStatusCode<L>, rng: &mut R) -> Self {
         let code: u16 = (100..600).fake_with_rng(rng);
         format!("{}", http::StatusCode::from_u16(code).unwrap())
     }
 }
+
+impl<L: Tables> Enumerate<RfcStatusCode<L>> for http::StatusCode {
+    type Iter = StatusCodes;
+
+    fn enumerate(_: &RfcStatusCode<L>) -> Self::Iter {
+        http::StatusCode::enumerate(&Faker)
+    }
+}
+
+impl<L: Tables> Enumerate<RfcStatusCode<L>> for String {
+    type Iter = core::iter::Map<StatusCodes, fn(http::StatusCode) -> String>;
+
+    fn enumerate(_: &RfcStatusCode<L>) -> Self::Iter {
+        http::StatusCode::enumerate(&Faker).map(|code| format!("{}", code))
+    }
+}
+
+impl<L: Tables> Enumerate<ValidStatusCode<L>> for http::StatusCode {
+    type Iter = core::iter::Map<core::ops::Range<u16>, fn(u16) -> http::StatusCode>;
+
+    fn enumerate(_: &ValidStatusCode<L>) -> Self::Iter {
+        (100..600).map(|code| http::StatusCode::from_u16(code).unwrap())
+    }
+}
+
+impl<L: Tables> Enumerate<ValidStatusCode<L>> for String {
+    type Iter = core::iter::Map<core::ops::Range<u16>, fn(u16) -> String>;
+
+    fn enumerate(_: &ValidStatusCode<L>) -> Self::Iter {
+        (100..600).map(|code| format!("{}", http::StatusCode::from_u16(code).unwrap()))
+    }
+}
@@ -1,170 +1,239 @@
 use crate::faker::internet::raw::*;
 use crate::faker::lorem::raw::Word;
 use crate::faker::name::raw::FirstName;
-use crate::locales::Data;
-use crate::{Dummy, Fake, Faker};
-use deunicode::AsciiChars;
-use rand::distr::{Distribution, Uniform};
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, Ascii, Lowercase};
+use crate::{Dummy, Fake, FakeWrite, Faker};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
+#[cfg(feature = "std")]
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-impl<L: Data> Dummy<FreeEmailProvider<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FreeEmailProvider<L>, rng: &mut R) -> Self {
-        let s = *L::INTERNET_FREE_EMAIL_PROVIDER.choose(rng).unwrap();
+impl<L: Tables> Dummy<FreeEmailProvider<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FreeEmailProvider<L>, rng: &mut R) -> Self {
+        let s =
+            *c.0.tables()
+                .INTERNET_FREE_EMAIL_PROVIDER
+                .choose(rng)
+                .unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<FreeEmailProvider<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FreeEmailProvider<L>, rng: &mut R) -> Self {
-        L::INTERNET_FREE_EMAIL_PROVIDER.choose(rng).unwrap()
+impl<L: Tables> Dummy<FreeEmailProvider<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FreeEmailProvider<L>, rng: &mut R) -> Self {
+        c.0.tables()
+            .INTERNET_FREE_EMAIL_PROVIDER
+            .choose(rng)
+            .unwrap()
     }
 }
 
-impl<L: Data> Dummy<DomainSuffix<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &DomainSuffix<L>, rng: &mut R) -> Self {
-        let s = *L::INTERNET_DOMAIN_SUFFIX.choose(rng).unwrap();
+impl<L: Tables> Dummy<DomainSuffix<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &DomainSuffix<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().INTERNET_DOMAIN_SUFFIX.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<DomainSuffix<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &DomainSuffix<L>, rng: &mut R) -> Self {
-        L::INTERNET_DOMAIN_SUFFIX.choose(rng).unwrap()
+impl<L: Tables> Dummy<DomainSuffix<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &DomainSuffix<L>, rng: &mut R) -> Self {
+        c.0.tables().INTERNET_DOMAIN_SUFFIX.choose(rng).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<FreeEmail<L>> for String {
+write_str_impl!(FreeEmailProvider, DomainSuffix, UserAgent);
+
+enumerate_table_impl!(
+    FreeEmailProvider => INTERNET_FREE_EMAIL_PROVIDER,
+    DomainSuffix => INTERNET_DOMAIN_SUFFIX,
+    UserAgent => INTERNET_USER_AGENT,
+);
+
+impl<L: Tables + Copy> FakeWrite for FreeEmail<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        Username(self.0).fake_into(&mut Ascii::new(&mut *w), rng)?;
+        w.write_char('@')?;
+        w.write_str(FreeEmailProvider(self.0).fake_with_rng(rng))
+    }
+}
+
+impl<L: Tables + Copy> Dummy<FreeEmail<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &FreeEmail<L>, rng: &mut R) -> Self {
-        let username: String = Username(c.0).fake_with_rng(rng);
-        let provider: String = FreeEmailProvider(c.0).fake_with_rng(rng);
-        format!("{}@{}", username.ascii_chars(), provider)
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<SafeEmail<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(c: &SafeEmail<L>, rng: &mut R) -> Self {
-        let username: String = FirstName(c.0).fake_with_rng::<&str, _>(rng).to_lowercase();
+impl<L: Tables + Copy> FakeWrite for SafeEmail<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let username: &str = FirstName(self.0).fake_with_rng(rng);
         let domain = ["com", "net", "org"].choose(rng).unwrap();
-        format!("{}@example.{}", username, domain)
+        Lowercase(&mut *w).write_str(username)?;
+        write!(w, "@example.{}", domain)
     }
 }
 
-impl<L: Data + Copy> Dummy<Username<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(c: &Username<L>, rng: &mut R) -> Self {
+impl<L: Tables + Copy> Dummy<SafeEmail<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &SafeEmail<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables + Copy> FakeWrite for Username<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let first_name = FirstName(self.0);
+        let word = Word(self.0);
         match Faker.fake_with_rng::<u8, _>(rng) {
-            0 => FirstName(c.0).fake_with_rng::<&str, _>(rng).to_lowercase(),
-            1 | 2 => format!(
-                "{}.{}",
-                Word(c.0).fake_with_rng::<&str, _>(rng),
-                FirstName(c.0).fake_with_rng::<&str, _>(rng).to_lowercase()
-            ),
-            3 | 4 => format!(
-                "{}{}",
-                FirstName(c.0).fake_with_rng::<&str, _>(rng).to_lowercase(),
-                Faker.fake_with_rng::<u8, _>(rng)
-            ),
-            _ => format!(
-                "{}_{}",
-                FirstName(c.0).fake_with_rng::<&str, _>(rng).to_lowercase(),
-                Word(c.0).fake_with_rng::<&str, _>(rng),
-            ),
+            0 => Lowercase(w).write_str(first_name.fake_with_rng(rng)),
+            1 | 2 => {
+                w.write_str(word.fake_with_rng(rng))?;
+                w.write_char('.')?;
+                Lowercase(w).write_str(first_name.fake_with_rng(rng))
+            }
+            3 | 4 => {
+                Lowercase(&mut *w).write_str(first_name.fake_with_rng(rng))?;
+                write!(w, "{}", Faker.fake_with_rng::<u8, _>(rng))
+            }
+            _ => {
+                Lowercase(&mut *w).write_str(first_name.fake_with_rng(rng))?;
+                w.write_char('_')?;
+                w.write_str(word.fake_with_rng(rng))
+            }
+        }
+    }
+}
+
+impl<L: Tables + Copy> Dummy<Username<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Username<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> FakeWrite for Password<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let len: usize = self.1.fake_with_rng(rng);
+        for _ in 0..len {
+            match self.0.tables().INTERNET_PASSWORD_CHARS.choose(rng) {
+                Some(&c) => w.write_char(c)?,
+                None => break,
+            }
         }
+        Ok(())
     }
 }
 
-impl<L: Data> Dummy<Password<L>> for String {
+impl<L: Tables> Dummy<Password<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Password<L>, rng: &mut R) -> Self {
-        let len: usize = c.1.fake_with_rng(rng);
-        let s: Option<String> = (0..len)
-            .map(|_| Some(*L::INTERNET_PASSWORD_CHARS.choose(rng)?))
-            .collect();
-        s.unwrap_or_default()
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<IPv4<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &IPv4<L>, rng: &mut R) -> Self {
-        let u = Uniform::new_inclusive(u8::MIN, u8::MAX).expect("u8::MIN <= u8::MAX");
-        format!(
-            "{}.{}.{}.{}",
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-        )
+impl<L: Tables> FakeWrite for IPv4<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let u = |rng: &mut R| (..).fake_with_rng::<u8, _>(rng);
+        write!(w, "{}.{}.{}.{}", u(rng), u(rng), u(rng), u(rng),)
     }
 }
 
-impl<L: Data> Dummy<IPv4<L>> for Ipv4Addr {
+impl<L: Tables> Dummy<IPv4<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &IPv4<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<IPv4<L>> for Ipv4Addr {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &IPv4<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<Ipv4Addr, _>(rng)
     }
 }
 
-impl<L: Data> Dummy<IPv6<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &IPv6<L>, rng: &mut R) -> Self {
-        let u = Uniform::new_inclusive(u16::MIN, u16::MAX).expect("u16::MIN <= u16::MAX");
-        format!(
+impl<L: Tables> FakeWrite for IPv6<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let u = |rng: &mut R| (..).fake_with_rng::<u16, _>(rng);
+        write!(
+            w,
             "{:X}:{:X}:{:X}:{:X}:{:X}:{:X}:{:X}:{:X}",
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
         )
     }
 }
 
-impl<L: Data> Dummy<IPv6<L>> for Ipv6Addr {
+impl<L: Tables> Dummy<IPv6<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &IPv6<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<IPv6<L>> for Ipv6Addr {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &IPv6<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<Ipv6Addr, _>(rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<IP<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(c: &IP<L>, _rng: &mut R) -> Self {
-        IPv4(c.0).fake()
+impl<L: Tables + Copy> FakeWrite for IP<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        IPv4(self.0).fake_into(w, rng)
+    }
+}
+
+impl<L: Tables + Copy> Dummy<IP<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &IP<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data> Dummy<IP<L>> for IpAddr {
+#[cfg(feature = "std")]
+impl<L: Tables> Dummy<IP<L>> for IpAddr {
     #[inline]
     fn dummy_with_rng<R: Rng + ?Sized>(_: &IP<L>, rng: &mut R) -> Self {
         Faker.fake_with_rng::<IpAddr, _>(rng)
     }
 }
 
-impl<L: Data> Dummy<MACAddress<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &MACAddress<L>, rng: &mut R) -> Self {
-        let u = Uniform::new_inclusive(u8::MIN, u8::MAX).expect("u8::MIN <= u8::MAX");
-        format!(
+impl<L: Tables> FakeWrite for MACAddress<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let u = |rng: &mut R| (..).fake_with_rng::<u8, _>(rng);
+        write!(
+            w,
             "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
         )
     }
 }
 
-impl<L: Data> Dummy<UserAgent<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &UserAgent<L>, rng: &mut R) -> Self {
-        let s = *L::INTERNET_USER_AGENT.choose(rng).unwrap();
+impl<L: Tables> Dummy<MACAddress<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &MACAddress<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> Dummy<UserAgent<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &UserAgent<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().INTERNET_USER_AGENT.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<UserAgent<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &UserAgent<L>, rng: &mut R) -> Self {
-        L::INTERNET_USER_AGENT.choose(rng).unwrap()
+impl<L: Tables> Dummy<UserAgent<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &UserAgent<L>, rng: &mut R) -> Self {
+        c.0.tables().INTERNET_USER_AGENT.choose(rng).unwrap()
     }
 }
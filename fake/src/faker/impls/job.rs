@@ -1,53 +1,78 @@
 use crate::faker::job::raw::*;
-use crate::locales::Data;
-use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, write_template};
+use crate::{Dummy, Fake, FakeWrite};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<Seniority<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Seniority<L>, rng: &mut R) -> Self {
-        let s = *L::JOB_SENIORITY.choose(rng).unwrap();
+impl<L: Tables> Dummy<Seniority<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Seniority<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().JOB_SENIORITY.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Seniority<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Seniority<L>, rng: &mut R) -> Self {
-        L::JOB_SENIORITY.choose(rng).unwrap()
+impl<L: Tables> Dummy<Seniority<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Seniority<L>, rng: &mut R) -> Self {
+        c.0.tables().JOB_SENIORITY.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<Field<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Field<L>, rng: &mut R) -> Self {
-        let s = *L::JOB_FIELD.choose(rng).unwrap();
+impl<L: Tables> Dummy<Field<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Field<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().JOB_FIELD.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Field<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Field<L>, rng: &mut R) -> Self {
-        L::JOB_FIELD.choose(rng).unwrap()
+impl<L: Tables> Dummy<Field<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Field<L>, rng: &mut R) -> Self {
+        c.0.tables().JOB_FIELD.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<Position<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Position<L>, rng: &mut R) -> Self {
-        let s = *L::JOB_POSITION.choose(rng).unwrap();
+impl<L: Tables> Dummy<Position<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Position<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().JOB_POSITION.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Position<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Position<L>, rng: &mut R) -> Self {
-        L::JOB_POSITION.choose(rng).unwrap()
+impl<L: Tables> Dummy<Position<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Position<L>, rng: &mut R) -> Self {
+        c.0.tables().JOB_POSITION.choose(rng).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<Title<L>> for String {
+write_str_impl!(Seniority, Field, Position);
+
+enumerate_table_impl!(
+    Seniority => JOB_SENIORITY,
+    Field => JOB_FIELD,
+    Position => JOB_POSITION,
+);
+
+impl<L: Tables + Copy> FakeWrite for Title<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let seniority: &str = Seniority(self.0).fake_with_rng(rng);
+        let field: &str = Field(self.0).fake_with_rng(rng);
+        let position: &str = Position(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().JOB_TITLE_TPL,
+            &[
+                ("{Seniority}", &seniority),
+                ("{Field}", &field),
+                ("{Position}", &position),
+            ],
+        )
+    }
+}
+
+impl<L: Tables + Copy> Dummy<Title<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Title<L>, rng: &mut R) -> Self {
-        L::JOB_TITLE_TPL
-            .replace("{Seniority}", Seniority(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{Field}", Field(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{Position}", Position(c.0).fake_with_rng::<&str, _>(rng))
+        fake_string(c, rng)
     }
 }
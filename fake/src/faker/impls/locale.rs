@@ -0,0 +1,316 @@
+//! Impls of the locale fakers for the runtime [`Locale`] and [`Mixed`],
+//! delegating to the impls of the static locale they name or pick.
+
+use crate::enumerate::{Table, TableStrings};
+use crate::locales::{with_locale, Locale, Mixed};
+use crate::{Dummy, Enumerate, Fake, FakeWrite};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use rand::Rng;
+
+macro_rules! locale_impls {
+    ($(
+        $(#[$meta:meta])*
+        $module:ident {
+            $($faker:ident $(<$lt:lifetime>)? => $($typ:ty),+;)+
+        }
+    )+) => {
+        $(
+            $(#[$meta])*
+            mod $module {
+                use super::*;
+                use crate::faker::$module::raw::*;
+
+                $(locale_impls!(@impl $faker $(<$lt>)? => $($typ),+);)+
+            }
+        )+
+    };
+    (@impl $faker:ident $(<$lt:lifetime>)? => $typ:ty $(, $rest:ty)*) => {
+        impl Dummy<$faker<$($lt,)? Locale>> for $typ {
+            fn dummy_with_rng<R: Rng + ?Sized>(c: &$faker<$($lt,)? Locale>, rng: &mut R) -> Self {
+                with_locale!(c.0, |l| c.for_locale(l).fake_with_rng(rng))
+            }
+        }
+
+        impl Dummy<$faker<$($lt,)? Mixed<'_>>> for $typ {
+            fn dummy_with_rng<R: Rng + ?Sized>(c: &$faker<$($lt,)? Mixed<'_>>, rng: &mut R) -> Self {
+                c.for_locale(c.0.pick(rng)).fake_with_rng(rng)
+            }
+        }
+
+        locale_impls!(@impl $faker $(<$lt>)? => $($rest),*);
+    };
+    (@impl $faker:ident $(<$lt:lifetime>)? =>) => {};
+}
+
+locale_impls! {
+    address {
+        CityPrefix => String, &str;
+        CitySuffix => String, &str;
+        CityName => String;
+        CountryName => String, &str;
+        CountryCode => String, &str;
+        StreetSuffix => String, &str;
+        StreetName => String;
+        TimeZone => String, &str;
+        StateName => String, &str;
+        StateAbbr => String, &str;
+        SecondaryAddressType => String, &str;
+        SecondaryAddress => String;
+        ZipCode => String;
+        PostCode => String;
+        BuildingNumber => String;
+        Latitude => String, f32, f64;
+        Longitude => String, f32, f64;
+        Geohash => String;
+    }
+    barcode {
+        Isbn => String;
+        Isbn10 => String;
+        Isbn13 => String;
+    }
+    boolean {
+        Boolean => bool;
+    }
+    #[cfg(feature = "random_color")]
+    color {
+        HexColor => String;
+        RgbColor => String;
+        RgbaColor => String;
+        HslColor => String;
+        HslaColor => String;
+        Color => String;
+    }
+    #[cfg(feature = "chrono")]
+    chrono {
+        Time => String, ::chrono::NaiveTime;
+        Date => String, ::chrono::NaiveDate;
+        DateTime => String, ::chrono::NaiveDateTime;
+        Duration => ::chrono::Duration;
+        DateTimeBefore => String, ::chrono::DateTime<::chrono::Utc>;
+        DateTimeAfter => String, ::chrono::DateTime<::chrono::Utc>;
+        DateTimeBetween => String, ::chrono::DateTime<::chrono::Utc>;
+    }
+    #[cfg(feature = "time")]
+    time {
+        Time => String, ::time::Time;
+        Date => String, ::time::Date;
+        DateTime => String, ::time::PrimitiveDateTime, ::time::OffsetDateTime;
+        Duration => ::time::Duration;
+        DateTimeBefore => String, ::time::OffsetDateTime;
+        DateTimeAfter => String, ::time::OffsetDateTime;
+        DateTimeBetween => String, ::time::OffsetDateTime;
+    }
+    creditcard {
+        CreditCardNumber => String;
+    }
+    company {
+        CompanySuffix => String, &str;
+        CompanyName => String;
+        Buzzword => String, &str;
+        BuzzwordMiddle => String, &str;
+        BuzzwordTail => String, &str;
+        CatchPhrase => String;
+        BsVerb => String, &str;
+        BsAdj => String, &str;
+        BsNoun => String, &str;
+        Bs => String;
+        Profession => String, &str;
+        Industry => String, &str;
+    }
+    #[cfg(feature = "http")]
+    http {
+        RfcStatusCode => String, ::http::StatusCode;
+        ValidStatusCode => String, ::http::StatusCode;
+    }
+    internet {
+        FreeEmailProvider => String, &str;
+        DomainSuffix => String, &str;
+        FreeEmail => String;
+        SafeEmail => String;
+        Username => String;
+        Password => String;
+        IPv4 => String;
+        IPv6 => String;
+        IP => String;
+        MACAddress => String;
+        UserAgent => String, &str;
+    }
+    job {
+        Seniority => String, &str;
+        Field => String, &str;
+        Position => String, &str;
+        Title => String;
+    }
+    lorem {
+        Word => String, &str;
+        Words => Vec<String>;
+        Sentence => String;
+        Sentences => Vec<String>;
+        Paragraph => String;
+        Paragraphs => Vec<String>;
+    }
+    name {
+        FirstName => String, &str;
+        LastName => String, &str;
+        Title => String, &str;
+        Suffix => String, &str;
+        Name => String;
+        NameWithTitle => String;
+    }
+    number {
+        Digit => String, &str;
+        NumberWithFormat<'_> => String;
+    }
+    phone_number {
+        PhoneNumber => String;
+        CellNumber => String;
+    }
+    filesystem {
+        FileName => String;
+        FileExtension => String, &str;
+        MimeType => String;
+        Semver => String;
+        SemverStable => String;
+        SemverUnstable => String;
+    }
+    currency {
+        CurrencyCode => String, &str;
+        CurrencyName => String, &str;
+        CurrencySymbol => String, &str;
+    }
+    finance {
+        Bic => String;
+        Isin => String;
+    }
+}
+
+macro_rules! write_impls {
+    ($($module:ident { $($faker:ident $(<$lt:lifetime>)?),+ })+) => {
+        $($(
+            impl FakeWrite for crate::faker::$module::raw::$faker<$($lt,)? Locale> {
+                fn fake_into<W: fmt::Write + ?Sized, R: Rng + ?Sized>(
+                    &self,
+                    w: &mut W,
+                    rng: &mut R,
+                ) -> fmt::Result {
+                    with_locale!(self.0, |l| self.for_locale(l).fake_into(w, rng))
+                }
+            }
+
+            impl FakeWrite for crate::faker::$module::raw::$faker<$($lt,)? Mixed<'_>> {
+                fn fake_into<W: fmt::Write + ?Sized, R: Rng + ?Sized>(
+                    &self,
+                    w: &mut W,
+                    rng: &mut R,
+                ) -> fmt::Result {
+                    self.for_locale(self.0.pick(rng)).fake_into(w, rng)
+                }
+            }
+        )+)+
+    };
+}
+
+write_impls! {
+    address {
+        CityPrefix, CitySuffix, CityName, CountryName, CountryCode, StreetSuffix, StreetName,
+        TimeZone, StateName, StateAbbr, SecondaryAddressType, SecondaryAddress, ZipCode, PostCode,
+        BuildingNumber, Latitude, Longitude, Geohash
+    }
+    creditcard { CreditCardNumber }
+    company {
+        CompanySuffix, CompanyName, Buzzword, BuzzwordMiddle, BuzzwordTail, CatchPhrase, BsVerb,
+        BsAdj, BsNoun, Bs, Profession, Industry
+    }
+    internet {
+        FreeEmailProvider, DomainSuffix, FreeEmail, SafeEmail, Username, Password, IPv4, IPv6, IP,
+        MACAddress, UserAgent
+    }
+    job { Seniority, Field, Position, Title }
+    lorem { Word, Sentence, Paragraph }
+    name { FirstName, LastName, Title, Suffix, Name, NameWithTitle }
+    number { Digit, NumberWithFormat<'_> }
+    phone_number { PhoneNumber, CellNumber }
+    currency { CurrencyCode, CurrencyName, CurrencySymbol }
+}
+
+macro_rules! enumerate_impls {
+    ($($module:ident { $($faker:ident),+ })+) => {
+        $($(
+            impl Enumerate<crate::faker::$module::raw::$faker<Locale>> for &'static str {
+                type Iter = Table;
+
+                fn enumerate(c: &crate::faker::$module::raw::$faker<Locale>) -> Table {
+                    with_locale!(c.0, |l| c.for_locale(l).fake_all::<&str>())
+                }
+            }
+
+            impl Enumerate<crate::faker::$module::raw::$faker<Locale>> for String {
+                type Iter = TableStrings;
+
+                fn enumerate(c: &crate::faker::$module::raw::$faker<Locale>) -> TableStrings {
+                    with_locale!(c.0, |l| c.for_locale(l).fake_all::<String>())
+                }
+            }
+        )+)+
+    };
+}
+
+enumerate_impls! {
+    address {
+        CityPrefix, CitySuffix, CountryName, CountryCode, StreetSuffix, TimeZone, StateName,
+        StateAbbr, SecondaryAddressType
+    }
+    company {
+        CompanySuffix, Buzzword, BuzzwordMiddle, BuzzwordTail, BsVerb, BsAdj, BsNoun, Profession,
+        Industry
+    }
+    currency { CurrencyCode, CurrencyName, CurrencySymbol }
+    filesystem { FileExtension }
+    internet { FreeEmailProvider, DomainSuffix, UserAgent }
+    job { Seniority, Field, Position }
+    lorem { Word }
+    name { FirstName, LastName, Title, Suffix }
+    number { Digit }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> Dummy<crate::faker::chrono::raw::DateTime<Locale>> for ::chrono::DateTime<Tz>
+where
+    Tz: ::chrono::TimeZone + Dummy<crate::Faker>,
+{
+    fn dummy_with_rng<R: Rng + ?Sized>(
+        c: &crate::faker::chrono::raw::DateTime<Locale>,
+        rng: &mut R,
+    ) -> Self {
+        with_locale!(c.0, |l| c.for_locale(l).fake_with_rng(rng))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> Dummy<crate::faker::chrono::raw::DateTime<Mixed<'_>>> for ::chrono::DateTime<Tz>
+where
+    Tz: ::chrono::TimeZone + Dummy<crate::Faker>,
+{
+    fn dummy_with_rng<R: Rng + ?Sized>(
+        c: &crate::faker::chrono::raw::DateTime<Mixed<'_>>,
+        rng: &mut R,
+    ) -> Self {
+        c.for_locale(c.0.pick(rng)).fake_with_rng(rng)
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::*;
+    use crate::faker::filesystem::raw::{DirPath, FilePath};
+    use crate::faker::internet::raw::{IPv4, IPv6, IP};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use std::path::PathBuf;
+
+    locale_impls!(@impl FilePath => String, PathBuf);
+    locale_impls!(@impl DirPath => String, PathBuf);
+    locale_impls!(@impl IPv4 => Ipv4Addr);
+    locale_impls!(@impl IPv6 => Ipv6Addr);
+    locale_impls!(@impl IP => IpAddr);
+}
@@ -1,23 +1,30 @@
 use crate::faker::lorem::raw::*;
-use crate::locales::Data;
-use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::fake_string;
+use crate::{Dummy, Fake, FakeWrite};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<Word<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Word<L>, rng: &mut R) -> Self {
-        let s = *L::LOREM_WORD.choose(rng).unwrap();
+impl<L: Tables> Dummy<Word<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Word<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().LOREM_WORD.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Word<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Word<L>, rng: &mut R) -> Self {
-        L::LOREM_WORD.choose(rng).unwrap()
+impl<L: Tables> Dummy<Word<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Word<L>, rng: &mut R) -> Self {
+        c.0.tables().LOREM_WORD.choose(rng).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<Words<L>> for Vec<String> {
+write_str_impl!(Word);
+
+enumerate_table_impl!(Word => LOREM_WORD);
+
+impl<L: Tables + Copy> Dummy<Words<L>> for Vec<String> {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Words<L>, rng: &mut R) -> Self {
         let len: usize = c.1.fake_with_rng(rng);
         let mut v: Vec<String> = Vec::with_capacity(len);
@@ -29,19 +36,27 @@ impl<L: Data + Copy> Dummy<Words<L>> for Vec<String> {
     }
 }
 
-impl<L: Data + Copy> Dummy<Sentence<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(c: &Sentence<L>, rng: &mut R) -> Self {
-        let len: usize = c.1.fake_with_rng(rng);
-        let mut v: Vec<String> = Vec::with_capacity(len);
-        let w = Word(c.0);
-        for _ in 0..len {
-            v.push(w.fake_with_rng(rng));
+impl<L: Tables + Copy> FakeWrite for Sentence<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let len: usize = self.1.fake_with_rng(rng);
+        let word = Word(self.0);
+        for i in 0..len {
+            if i > 0 {
+                w.write_char(' ')?;
+            }
+            w.write_str(word.fake_with_rng(rng))?;
         }
-        v.join(" ") + "."
+        w.write_char('.')
+    }
+}
+
+impl<L: Tables + Copy> Dummy<Sentence<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Sentence<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<Sentences<L>> for Vec<String> {
+impl<L: Tables + Copy> Dummy<Sentences<L>> for Vec<String> {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Sentences<L>, rng: &mut R) -> Self {
         let len: usize = c.1.fake_with_rng(rng);
         let mut v: Vec<String> = Vec::with_capacity(len);
@@ -53,19 +68,27 @@ impl<L: Data + Copy> Dummy<Sentences<L>> for Vec<String> {
     }
 }
 
-impl<L: Data + Copy> Dummy<Paragraph<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(c: &Paragraph<L>, rng: &mut R) -> Self {
-        let len: usize = c.1.fake_with_rng(rng);
-        let mut v: Vec<String> = Vec::with_capacity(len);
-        let s = Sentence(c.0, 4..10);
-        for _ in 0..len {
-            v.push(s.fake_with_rng(rng));
+impl<L: Tables + Copy> FakeWrite for Paragraph<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let len: usize = self.1.fake_with_rng(rng);
+        let sentence = Sentence(self.0, 4..10);
+        for i in 0..len {
+            if i > 0 {
+                w.write_char('\n')?;
+            }
+            sentence.fake_into(w, rng)?;
         }
-        v.join("\n")
+        Ok(())
+    }
+}
+
+impl<L: Tables + Copy> Dummy<Paragraph<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Paragraph<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
     }
 }
 
-impl<L: Data + Copy> Dummy<Paragraphs<L>> for Vec<String> {
+impl<L: Tables + Copy> Dummy<Paragraphs<L>> for Vec<String> {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Paragraphs<L>, rng: &mut R) -> Self {
         let len: usize = c.1.fake_with_rng(rng);
         let mut v: Vec<String> = Vec::with_capacity(len);
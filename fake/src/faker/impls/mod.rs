@@ -1,3 +1,45 @@
+/// [`FakeWrite`](crate::FakeWrite) impls of fakers picking a string out of
+/// a locale table, writing their `&str`.
+macro_rules! write_str_impl {
+    ($($faker:ident),+) => {
+        $(
+            impl<L: Tables> crate::FakeWrite for $faker<L> {
+                fn fake_into<W: core::fmt::Write + ?Sized, R: Rng + ?Sized>(
+                    &self,
+                    w: &mut W,
+                    rng: &mut R,
+                ) -> core::fmt::Result {
+                    w.write_str(<&str as Dummy<Self>>::dummy_with_rng(self, rng))
+                }
+            }
+        )+
+    };
+}
+
+/// [`Enumerate`](crate::Enumerate) impls of fakers picking a string out of
+/// a locale table, listing the table.
+macro_rules! enumerate_table_impl {
+    ($($faker:ident => $table:ident),+ $(,)?) => {
+        $(
+            impl<L: Tables> crate::Enumerate<$faker<L>> for &'static str {
+                type Iter = crate::enumerate::Table;
+
+                fn enumerate(c: &$faker<L>) -> Self::Iter {
+                    crate::enumerate::table(c.0.tables().$table)
+                }
+            }
+
+            impl<L: Tables> crate::Enumerate<$faker<L>> for String {
+                type Iter = crate::enumerate::TableStrings;
+
+                fn enumerate(c: &$faker<L>) -> Self::Iter {
+                    crate::enumerate::table_strings(c.0.tables().$table)
+                }
+            }
+        )+
+    };
+}
+
 pub mod address;
 pub mod administrative;
 pub mod automotive;
@@ -16,6 +58,7 @@ pub mod finance;
 pub mod http;
 pub mod internet;
 pub mod job;
+mod locale;
 pub mod lorem;
 pub mod name;
 pub mod number;
@@ -1,74 +1,110 @@
 use crate::faker::name::raw::*;
-use crate::locales::Data;
-use crate::{Dummy, Fake};
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, write_template};
+use crate::{Dummy, Fake, FakeWrite};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<FirstName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FirstName<L>, rng: &mut R) -> Self {
-        let s = *L::NAME_FIRST_NAME.choose(rng).unwrap();
+impl<L: Tables> Dummy<FirstName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FirstName<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().NAME_FIRST_NAME.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<FirstName<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &FirstName<L>, rng: &mut R) -> Self {
-        L::NAME_FIRST_NAME.choose(rng).unwrap()
+impl<L: Tables> Dummy<FirstName<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &FirstName<L>, rng: &mut R) -> Self {
+        c.0.tables().NAME_FIRST_NAME.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<LastName<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &LastName<L>, rng: &mut R) -> Self {
-        let s = *L::NAME_LAST_NAME.choose(rng).unwrap();
+impl<L: Tables> Dummy<LastName<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &LastName<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().NAME_LAST_NAME.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<LastName<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &LastName<L>, rng: &mut R) -> Self {
-        L::NAME_LAST_NAME.choose(rng).unwrap()
+impl<L: Tables> Dummy<LastName<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &LastName<L>, rng: &mut R) -> Self {
+        c.0.tables().NAME_LAST_NAME.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<Title<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Title<L>, rng: &mut R) -> Self {
-        let s = *L::NAME_TITLE.choose(rng).unwrap();
+impl<L: Tables> Dummy<Title<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Title<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().NAME_TITLE.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Title<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Title<L>, rng: &mut R) -> Self {
-        L::NAME_TITLE.choose(rng).unwrap()
+impl<L: Tables> Dummy<Title<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Title<L>, rng: &mut R) -> Self {
+        c.0.tables().NAME_TITLE.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<Suffix<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Suffix<L>, rng: &mut R) -> Self {
-        let s = *L::NAME_SUFFIX.choose(rng).unwrap();
+impl<L: Tables> Dummy<Suffix<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Suffix<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().NAME_SUFFIX.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Suffix<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Suffix<L>, rng: &mut R) -> Self {
-        L::NAME_SUFFIX.choose(rng).unwrap()
+impl<L: Tables> Dummy<Suffix<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Suffix<L>, rng: &mut R) -> Self {
+        c.0.tables().NAME_SUFFIX.choose(rng).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<Name<L>> for String {
+write_str_impl!(FirstName, LastName, Title, Suffix);
+
+enumerate_table_impl!(
+    FirstName => NAME_FIRST_NAME,
+    LastName => NAME_LAST_NAME,
+    Title => NAME_TITLE,
+    Suffix => NAME_SUFFIX,
+);
+
+impl<L: Tables + Copy> FakeWrite for Name<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let first_name: &str = FirstName(self.0).fake_with_rng(rng);
+        let last_name: &str = LastName(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().NAME_TPL,
+            &[("{FirstName}", &first_name), ("{LastName}", &last_name)],
+        )
+    }
+}
+
+impl<L: Tables + Copy> Dummy<Name<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &Name<L>, rng: &mut R) -> Self {
-        L::NAME_TPL
-            .replace("{FirstName}", FirstName(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{LastName}", LastName(c.0).fake_with_rng::<&str, _>(rng))
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables + Copy> FakeWrite for NameWithTitle<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let title: &str = Title(self.0).fake_with_rng(rng);
+        let first_name: &str = FirstName(self.0).fake_with_rng(rng);
+        let last_name: &str = LastName(self.0).fake_with_rng(rng);
+        write_template(
+            w,
+            self.0.tables().NAME_WITH_TITLE_TPL,
+            &[
+                ("{Title}", &title),
+                ("{FirstName}", &first_name),
+                ("{LastName}", &last_name),
+            ],
+        )
     }
 }
 
-impl<L: Data + Copy> Dummy<NameWithTitle<L>> for String {
+impl<L: Tables + Copy> Dummy<NameWithTitle<L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &NameWithTitle<L>, rng: &mut R) -> Self {
-        L::NAME_WITH_TITLE_TPL
-            .replace("{Title}", Title(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{FirstName}", FirstName(c.0).fake_with_rng::<&str, _>(rng))
-            .replace("{LastName}", LastName(c.0).fake_with_rng::<&str, _>(rng))
+        fake_string(c, rng)
     }
 }
@@ -1,25 +1,37 @@
 use crate::faker::number::raw::*;
-use crate::faker::numerify_sym;
-use crate::locales::Data;
-use crate::Dummy;
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, write_numerified};
+use crate::{Dummy, FakeWrite};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<Digit<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Digit<L>, rng: &mut R) -> Self {
-        let s = *L::NUMBER_DIGIT.choose(rng).unwrap();
+impl<L: Tables> Dummy<Digit<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Digit<L>, rng: &mut R) -> Self {
+        let s = *c.0.tables().NUMBER_DIGIT.choose(rng).unwrap();
         s.into()
     }
 }
 
-impl<L: Data> Dummy<Digit<L>> for &str {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &Digit<L>, rng: &mut R) -> Self {
-        L::NUMBER_DIGIT.choose(rng).unwrap()
+impl<L: Tables> Dummy<Digit<L>> for &str {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &Digit<L>, rng: &mut R) -> Self {
+        c.0.tables().NUMBER_DIGIT.choose(rng).unwrap()
     }
 }
 
-impl<L: Data> Dummy<NumberWithFormat<'_, L>> for String {
+write_str_impl!(Digit);
+
+enumerate_table_impl!(Digit => NUMBER_DIGIT);
+
+impl<L: Tables> FakeWrite for NumberWithFormat<'_, L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        write_numerified(w, self.1, rng)
+    }
+}
+
+impl<L: Tables> Dummy<NumberWithFormat<'_, L>> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &NumberWithFormat<L>, rng: &mut R) -> Self {
-        numerify_sym(c.1, rng)
+        fake_string(c, rng)
     }
 }
@@ -1,20 +1,39 @@
-use crate::faker::numerify_sym;
 use crate::faker::phone_number::raw::*;
-use crate::locales::Data;
-use crate::Dummy;
-use rand::seq::IndexedRandom;
+use crate::locales::Tables;
+use crate::stable::IndexedRandom;
+use crate::write::{fake_string, write_numerified};
+use crate::{Dummy, FakeWrite};
+use alloc::string::String;
+use core::fmt::{self, Write};
 use rand::Rng;
 
-impl<L: Data> Dummy<PhoneNumber<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_: &PhoneNumber<L>, rng: &mut R) -> Self {
-        let fmt = L::PHONE_NUMBER_FORMATS.choose(rng).unwrap();
-        numerify_sym(fmt, rng)
+impl<L: Tables> FakeWrite for PhoneNumber<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let fmt = self.0.tables().PHONE_NUMBER_FORMATS.choose(rng).unwrap();
+        write_numerified(w, fmt, rng)
     }
 }
 
-impl<L: Data> Dummy<CellNumber<L>> for String {
-    fn dummy_with_rng<R: Rng + ?Sized>(_c: &CellNumber<L>, rng: &mut R) -> Self {
-        let fmt = L::PHONE_CELL_NUMBER_FORMATS.choose(rng).unwrap();
-        numerify_sym(fmt, rng)
+impl<L: Tables> Dummy<PhoneNumber<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &PhoneNumber<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
+    }
+}
+
+impl<L: Tables> FakeWrite for CellNumber<L> {
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result {
+        let fmt = self
+            .0
+            .tables()
+            .PHONE_CELL_NUMBER_FORMATS
+            .choose(rng)
+            .unwrap();
+        write_numerified(w, fmt, rng)
+    }
+}
+
+impl<L: Tables> Dummy<CellNumber<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(c: &CellNumber<L>, rng: &mut R) -> Self {
+        fake_string(c, rng)
     }
 }
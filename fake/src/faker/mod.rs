@@ -1,17 +1,11 @@
-use crate::Fake;
+use alloc::string::String;
 use rand::Rng;
-use std::char;
 
 #[inline]
 fn numerify_sym<R: Rng + ?Sized>(string: &str, rng: &mut R) -> String {
-    string
-        .chars()
-        .map(|x| match x {
-            '^' => char::from_digit((1..10).fake_with_rng::<u32, _>(rng), 10).unwrap(),
-            '#' => char::from_digit((0..10).fake_with_rng::<u32, _>(rng), 10).unwrap(),
-            other => other,
-        })
-        .collect()
+    let mut s = String::with_capacity(string.len());
+    crate::write::write_numerified(&mut s, string, rng).unwrap();
+    s
 }
 
 macro_rules! def_fakers {
@@ -32,6 +26,15 @@ macro_rules! def_fakers {
         pub mod raw {
         $(
             pub struct $name<$( $($lts),* , )?L>(pub L, $(pub $typ),*);
+
+            impl<$( $($lts),* , )?L> $name<$( $($lts),* , )?L> {
+                /// Same faker for another locale.
+                #[allow(dead_code, clippy::clone_on_copy)]
+                pub(crate) fn for_locale<L2>(&self, locale: L2) -> $name<$( $($lts),* , )?L2> {
+                    let $name(_, $($arg),*) = self;
+                    $name(locale, $($arg.clone()),*)
+                }
+            }
         )+
         }
 
@@ -45,12 +48,12 @@ macro_rules! def_fakers {
         def_fakers!(@m pt_pt=>PT_PT {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
         def_fakers!(@m de_de=>DE_DE {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
         def_fakers!(@m it_it=>IT_IT {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
-        def_fakers!(@m it_it=>DE_CH {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
-        def_fakers!(@m it_it=>DE_AT {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
-        def_fakers!(@m it_it=>DE_LI {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
+        def_fakers!(@m de_ch=>DE_CH {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
+        def_fakers!(@m de_at=>DE_AT {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
+        def_fakers!(@m de_li=>DE_LI {$($name$(< $($lts),* >)?($($arg:$typ),*);)+});
+
 
 
-        
 
     };
 }
@@ -170,7 +173,7 @@ pub mod internet {
         FreeEmail();
         SafeEmail();
         Username();
-        Password(len_range: std::ops::Range<usize>);
+        Password(len_range: core::ops::Range<usize>);
         IPv4();
         IPv6();
         IP();
@@ -191,11 +194,11 @@ pub mod job {
 pub mod lorem {
     def_fakers! {
         Word();
-        Words(count: std::ops::Range<usize>);
-        Sentence(count: std::ops::Range<usize>);
-        Sentences(count: std::ops::Range<usize>);
-        Paragraph(count: std::ops::Range<usize>);
-        Paragraphs(count: std::ops::Range<usize>);
+        Words(count: core::ops::Range<usize>);
+        Sentence(count: core::ops::Range<usize>);
+        Sentences(count: core::ops::Range<usize>);
+        Paragraph(count: core::ops::Range<usize>);
+        Paragraphs(count: core::ops::Range<usize>);
     }
 }
 
@@ -255,6 +258,8 @@ pub mod finance {
 pub mod administrative {
     def_fakers! {
         HealthInsuranceCode();
+        FiscalCode();
+        TaxIdentificationNumber();
     }
 }
 
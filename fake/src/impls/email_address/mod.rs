@@ -5,17 +5,17 @@ use rand::Rng;
 
 use crate::{
     faker::internet::raw::{FreeEmail, SafeEmail},
-    locales::Data,
+    locales::Tables,
     Dummy,
 };
 
-impl<L: Data + Copy> Dummy<FreeEmail<L>> for EmailAddress {
+impl<L: Tables + Copy> Dummy<FreeEmail<L>> for EmailAddress {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &FreeEmail<L>, rng: &mut R) -> Self {
         Self::from_str(&<String as Dummy<FreeEmail<L>>>::dummy_with_rng(c, rng)).unwrap()
     }
 }
 
-impl<L: Data + Copy> Dummy<SafeEmail<L>> for EmailAddress {
+impl<L: Tables + Copy> Dummy<SafeEmail<L>> for EmailAddress {
     fn dummy_with_rng<R: Rng + ?Sized>(c: &SafeEmail<L>, rng: &mut R) -> Self {
         Self::from_str(&<String as Dummy<SafeEmail<L>>>::dummy_with_rng(c, rng)).unwrap()
     }
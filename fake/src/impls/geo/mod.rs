@@ -6,6 +6,19 @@ use rand::Rng;
 
 use crate::{Dummy, Fake, Faker};
 
+// `CoordNum` includes floats, which are neither `Hash` nor `Ord`, so
+// `crate::Unique` does not apply here. Only a handful of values are needed.
+fn distinct<U: Dummy<Faker> + PartialEq, R: Rng + ?Sized>(rng: &mut R, len: usize) -> Vec<U> {
+    let mut set = Vec::<U>::with_capacity(len);
+    while set.len() != len {
+        let new_item: U = Faker.fake_with_rng(rng);
+        if !set.contains(&new_item) {
+            set.push(new_item);
+        }
+    }
+    set
+}
+
 impl<T: CoordNum + Dummy<Faker>> Dummy<Faker> for geo_types::Coord<T> {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
         geo_types::Coord::<T> {
@@ -64,7 +77,7 @@ impl<T: CoordNum + Dummy<Faker>> Dummy<Faker> for geo_types::MultiPolygon<T> {
 impl<T: CoordNum + Dummy<Faker>> Dummy<Faker> for geo_types::Rect<T> {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
         // Rect points can not overlap.
-        let nums: Vec<T> = crate::unique::<T, _>(rng, 4);
+        let nums: Vec<T> = distinct::<T, _>(rng, 4);
         let coord_1 = geo_types::Coord::<T> {
             x: nums[0],
             y: nums[1],
@@ -93,7 +106,7 @@ impl<T: CoordNum + Dummy<Faker>> Dummy<Faker> for geo_types::Triangle<T> {
             let delta_y: f64 = cast(max_y - min_y).unwrap();
             delta_y / delta_x
         }
-        let nums: Vec<T> = crate::unique::<T, _>(rng, 6);
+        let nums: Vec<T> = distinct::<T, _>(rng, 6);
         let coord_1 = geo_types::Coord::<T> {
             x: nums[0],
             y: nums[1],
@@ -1,6 +1,6 @@
-use crate::{Dummy, Fake, Faker};
+use crate::stable::IndexedRandom;
+use crate::{Dummy, Enumerate, Fake, Faker};
 use http::uri;
-use rand::seq::IndexedRandom;
 use rand::Rng;
 use std::mem;
 use std::net::Ipv4Addr;
@@ -13,6 +13,18 @@ const RFC_STATUS_CODES: &[u16] = &[
     508, 510, 511,
 ];
 
+const METHODS: [http::Method; 9] = [
+    http::Method::GET,
+    http::Method::POST,
+    http::Method::PUT,
+    http::Method::DELETE,
+    http::Method::HEAD,
+    http::Method::OPTIONS,
+    http::Method::CONNECT,
+    http::Method::PATCH,
+    http::Method::TRACE,
+];
+
 const VALID_SCHEME_CHARACTERS: &[char] = &[
     'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
     't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L',
@@ -22,18 +34,8 @@ const VALID_SCHEME_CHARACTERS: &[char] = &[
 
 impl Dummy<Faker> for http::Method {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
-        let i: u8 = (0..9).fake_with_rng(rng);
-        match i {
-            0 => http::Method::GET,
-            1 => http::Method::POST,
-            2 => http::Method::PUT,
-            3 => http::Method::DELETE,
-            4 => http::Method::HEAD,
-            5 => http::Method::OPTIONS,
-            6 => http::Method::CONNECT,
-            7 => http::Method::PATCH,
-            _ => http::Method::TRACE,
-        }
+        let i: u8 = (0..METHODS.len() as u8).fake_with_rng(rng);
+        METHODS[usize::from(i)].clone()
     }
 }
 
@@ -90,6 +92,28 @@ impl Dummy<&[u16]> for http::StatusCode {
     }
 }
 
+/// Iterator over the RFC status codes.
+pub(crate) type StatusCodes =
+    core::iter::Map<core::slice::Iter<'static, u16>, fn(&u16) -> http::StatusCode>;
+
+impl Enumerate<Faker> for http::StatusCode {
+    type Iter = StatusCodes;
+
+    fn enumerate(_: &Faker) -> Self::Iter {
+        RFC_STATUS_CODES
+            .iter()
+            .map(|code| http::StatusCode::from_u16(*code).unwrap())
+    }
+}
+
+impl Enumerate<Faker> for http::Method {
+    type Iter = core::array::IntoIter<http::Method, 9>;
+
+    fn enumerate(_: &Faker) -> Self::Iter {
+        IntoIterator::into_iter(METHODS)
+    }
+}
+
 impl Dummy<Faker> for http::Version {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
         let i: u8 = (0..4).fake_with_rng(rng);
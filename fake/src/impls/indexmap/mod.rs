@@ -1,3 +1,4 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake, Faker};
 use indexmap::{IndexMap, IndexSet};
 use rand::Rng;
@@ -15,6 +16,7 @@ where
         let len = get_len(config, rng);
         let mut m = IndexMap::with_capacity_and_hasher(len, S::default());
         for _ in 0..len {
+            let _element = ElementRecord::start();
             m.insert(config.fake_with_rng(rng), config.fake_with_rng(rng));
         }
         m
@@ -30,6 +32,7 @@ where
         let len = get_len(config, rng);
         let mut m = IndexSet::with_capacity_and_hasher(len, S::default());
         for _ in 0..len {
+            let _element = ElementRecord::start();
             m.insert(config.fake_with_rng(rng));
         }
         m
@@ -24,6 +24,8 @@ pub mod glam;
 pub mod http;
 #[cfg(feature = "indexmap")]
 pub mod indexmap;
+#[cfg(feature = "regex")]
+pub mod regex;
 #[cfg(feature = "semver")]
 pub mod semver;
 #[cfg(feature = "serde_json")]
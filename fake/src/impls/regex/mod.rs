@@ -0,0 +1,151 @@
+use crate::{Dummy, Fake};
+use rand::Rng;
+use regex_syntax::hir::{Class, ClassUnicode, ClassUnicodeRange, Hir, HirKind};
+use regex_syntax::ParserBuilder;
+
+const DEFAULT_MAX_REPEAT: u32 = 8;
+
+/// Fake [`String`] matching a regular expression.
+///
+/// The pattern is parsed on every generation, use [`RegexFaker`] to parse it
+/// once. Panics if the pattern is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Fake, Regex};
+///
+/// let code: String = Regex("[A-Z]{2}-\\d{4}").fake();
+/// assert_eq!(code.len(), 7);
+/// ```
+pub struct Regex<'a>(pub &'a str);
+
+/// Fake [`String`] generator for a pre-parsed regular expression.
+///
+/// Supports literals, classes, repetitions, alternations and groups. Unbounded
+/// repetitions such as `*`, `+` or `{2,}` produce at most `max_repeat` extra
+/// items, 8 by default. Look-around assertions like `^`, `$` or `\b` are
+/// ignored.
+///
+/// Classes which overlap printable ASCII only produce printable ASCII
+/// characters, so `\d` yields `0-9` and `.` never yields control characters.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Fake, RegexFaker};
+///
+/// let f = RegexFaker::new("(ORD|INV)-[0-9a-f]{6}(-x+)?").unwrap().max_repeat(3);
+/// let id: String = f.fake();
+/// assert!(id.starts_with("ORD-") || id.starts_with("INV-"));
+/// ```
+pub struct RegexFaker {
+    hir: Hir,
+    max_repeat: u32,
+}
+
+impl RegexFaker {
+    /// Parse `pattern`, returning the parse error if it is invalid.
+    pub fn new(pattern: &str) -> Result<Self, Box<regex_syntax::Error>> {
+        let hir = ParserBuilder::new().build().parse(pattern)?;
+        Ok(RegexFaker {
+            hir,
+            max_repeat: DEFAULT_MAX_REPEAT,
+        })
+    }
+
+    /// Cap on the number of extra items produced by unbounded repetitions.
+    pub fn max_repeat(mut self, max_repeat: u32) -> Self {
+        self.max_repeat = max_repeat;
+        self
+    }
+
+    fn generate<R: Rng + ?Sized>(&self, hir: &Hir, out: &mut String, rng: &mut R) {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => {}
+            HirKind::Literal(lit) => out.push_str(&String::from_utf8_lossy(&lit.0)),
+            HirKind::Class(Class::Unicode(class)) => {
+                if let Some(c) = sample_unicode(class, rng) {
+                    out.push(c);
+                }
+            }
+            HirKind::Class(Class::Bytes(class)) => {
+                let total: u32 = class
+                    .ranges()
+                    .iter()
+                    .map(|r| u32::from(r.end() - r.start()) + 1)
+                    .sum();
+                if total > 0 {
+                    let mut n = (0..total).fake_with_rng::<u32, _>(rng);
+                    for r in class.ranges() {
+                        let size = u32::from(r.end() - r.start()) + 1;
+                        if n < size {
+                            out.push(char::from(r.start() + n as u8));
+                            break;
+                        }
+                        n -= size;
+                    }
+                }
+            }
+            HirKind::Repetition(rep) => {
+                let max = rep
+                    .max
+                    .unwrap_or_else(|| rep.min.saturating_add(self.max_repeat));
+                let count = (rep.min..=max).fake_with_rng::<u32, _>(rng);
+                for _ in 0..count {
+                    self.generate(&rep.sub, out, rng);
+                }
+            }
+            HirKind::Capture(capture) => self.generate(&capture.sub, out, rng),
+            HirKind::Concat(subs) => {
+                for sub in subs {
+                    self.generate(sub, out, rng);
+                }
+            }
+            HirKind::Alternation(subs) => {
+                let sub = &subs[(0..subs.len()).fake_with_rng::<usize, _>(rng)];
+                self.generate(sub, out, rng);
+            }
+        }
+    }
+}
+
+fn sample_unicode<R: Rng + ?Sized>(class: &ClassUnicode, rng: &mut R) -> Option<char> {
+    let mut printable = class.clone();
+    printable.intersect(&ClassUnicode::new([ClassUnicodeRange::new(' ', '~')]));
+    let class = if printable.ranges().is_empty() {
+        class
+    } else {
+        &printable
+    };
+
+    let size = |r: &ClassUnicodeRange| u32::from(r.end()) - u32::from(r.start()) + 1;
+    let total: u32 = class.ranges().iter().map(size).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut n = (0..total).fake_with_rng::<u32, _>(rng);
+    for r in class.ranges() {
+        if n < size(r) {
+            // ranges spanning the surrogate gap fall back to their start
+            return Some(char::from_u32(u32::from(r.start()) + n).unwrap_or(r.start()));
+        }
+        n -= size(r);
+    }
+    None
+}
+
+impl Dummy<RegexFaker> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &RegexFaker, rng: &mut R) -> Self {
+        let mut out = String::new();
+        config.generate(&config.hir, &mut out, rng);
+        out
+    }
+}
+
+impl Dummy<Regex<'_>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Regex<'_>, rng: &mut R) -> Self {
+        let faker = RegexFaker::new(config.0).expect("valid regex");
+        String::dummy_with_rng(&faker, rng)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake};
 use rand::Rng;
 
@@ -6,6 +7,9 @@ where
     T: Dummy<U>,
 {
     fn dummy_with_rng<R: Rng + ?Sized>(config: &U, rng: &mut R) -> Self {
-        std::array::from_fn(|_| Fake::fake_with_rng::<T, _>(config, rng))
+        core::array::from_fn(|_| {
+            let _element = ElementRecord::start();
+            Fake::fake_with_rng::<T, _>(config, rng)
+        })
     }
 }
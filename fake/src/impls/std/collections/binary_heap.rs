@@ -1,6 +1,7 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake, Faker};
+use alloc::collections::BinaryHeap;
 use rand::Rng;
-use std::collections::BinaryHeap;
 
 impl<T> Dummy<Faker> for BinaryHeap<T>
 where
@@ -10,6 +11,7 @@ where
         let len = super::get_len(config, rng);
         let mut v = BinaryHeap::with_capacity(len);
         for _ in 0..len {
+            let _element = ElementRecord::start();
             v.push(config.fake_with_rng(rng));
         }
         v
@@ -25,6 +27,7 @@ where
         let len: usize = config.1.fake_with_rng(rng);
         let mut v = BinaryHeap::with_capacity(len);
         for _ in 0..len {
+            let _element = ElementRecord::start();
             v.push(config.0.fake_with_rng(rng));
         }
         v
@@ -1,6 +1,7 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake, Faker};
+use alloc::collections::BTreeMap;
 use rand::Rng;
-use std::collections::BTreeMap;
 
 impl<K, V> Dummy<Faker> for BTreeMap<K, V>
 where
@@ -11,6 +12,7 @@ where
         let len = super::get_len(config, rng);
         let mut m = BTreeMap::new();
         for _ in 0..len {
+            let _element = ElementRecord::start();
             m.insert(config.fake_with_rng(rng), config.fake_with_rng(rng));
         }
         m
@@ -1,6 +1,7 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake, Faker};
+use alloc::collections::BTreeSet;
 use rand::Rng;
-use std::collections::BTreeSet;
 
 impl<T> Dummy<Faker> for BTreeSet<T>
 where
@@ -10,6 +11,7 @@ where
         let len = super::get_len(config, rng);
         let mut m = BTreeSet::new();
         for _ in 0..len {
+            let _element = ElementRecord::start();
             m.insert(config.fake_with_rng(rng));
         }
         m
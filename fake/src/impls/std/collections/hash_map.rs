@@ -1,3 +1,4 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake, Faker};
 use rand::Rng;
 use std::collections::HashMap;
@@ -13,6 +14,7 @@ where
         let len = super::get_len(config, rng);
         let mut m = HashMap::with_capacity_and_hasher(len, S::default());
         for _ in 0..len {
+            let _element = ElementRecord::start();
             m.insert(config.fake_with_rng(rng), config.fake_with_rng(rng));
         }
         m
@@ -1,15 +1,13 @@
-use std::ops::Range;
-
 use rand::Rng;
 
-use crate::{Fake, Faker};
-
-const DEFAULT_LEN_RANGE: Range<usize> = 0..10;
+use crate::{Fake, Faker, FakerConfig};
 
 pub mod binary_heap;
 pub mod btree_map;
 pub mod btree_set;
+#[cfg(feature = "std")]
 pub mod hash_map;
+#[cfg(feature = "std")]
 pub mod hash_set;
 pub mod linked_list;
 pub mod vec;
@@ -17,11 +15,11 @@ pub mod vec_deque;
 
 #[allow(unused_mut, unused_variables)]
 pub fn get_len<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> usize {
-    let mut range = DEFAULT_LEN_RANGE;
+    let mut range = FakerConfig::current().collection_len_range();
     #[cfg(feature = "maybe-non-empty-collections")]
-    if config.fake_with_rng(rng) {
+    if config.fake_with_rng(rng) && range.end > 1 {
         // allow to use AlwaysTrueRng to generate non-empty collections
-        range.start = 1;
+        range.start = range.start.max(1);
     }
     range.fake_with_rng(rng)
 }
@@ -1,4 +1,6 @@
+use crate::locales::ElementRecord;
 use crate::{Dummy, Fake, Faker};
+use alloc::vec::Vec;
 use rand::Rng;
 
 impl<T> Dummy<Faker> for Vec<T>
@@ -9,6 +11,7 @@ where
         let len = super::get_len(config, rng);
         let mut v = Vec::with_capacity(len);
         for _ in 0..len {
+            let _element = ElementRecord::start();
             v.push(config.fake_with_rng(rng));
         }
         v
@@ -24,6 +27,7 @@ where
         let len: usize = config.1.fake_with_rng(rng);
         let mut v = Vec::with_capacity(len);
         for _ in 0..len {
+            let _element = ElementRecord::start();
             v.push(config.0.fake_with_rng(rng));
         }
         v
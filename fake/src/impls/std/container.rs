@@ -1,11 +1,14 @@
 use crate::Dummy;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::cell::{Cell, RefCell};
+use core::ops::Deref;
+use core::pin::Pin;
 use rand::Rng;
-use std::borrow::Cow;
-use std::cell::{Cell, RefCell};
-use std::ops::Deref;
-use std::pin::Pin;
-use std::rc::Rc;
-use std::sync::{Arc, Mutex, RwLock};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, RwLock};
 
 macro_rules! container_impl {
     ($ptr:ident) => {
@@ -25,7 +28,9 @@ container_impl!(Cell);
 container_impl!(RefCell);
 container_impl!(Rc);
 container_impl!(Arc);
+#[cfg(feature = "std")]
 container_impl!(Mutex);
+#[cfg(feature = "std")]
 container_impl!(RwLock);
 
 impl<T, U> Dummy<U> for Pin<T>
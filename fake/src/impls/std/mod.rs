@@ -1,12 +1,18 @@
 pub mod array;
 pub mod collections;
 pub mod container;
+#[cfg(feature = "std")]
 pub mod net;
 pub mod num;
 pub mod option;
+#[cfg(feature = "std")]
 pub mod path;
 pub mod primitives;
 pub mod result;
+#[cfg(target_has_atomic = "64")]
+pub mod sequence;
 pub mod string;
 pub mod time;
 pub mod tuple;
+#[cfg(feature = "std")]
+pub mod unique;
@@ -1,27 +1,26 @@
 use crate::{Dummy, Fake, Faker};
-use rand::distr::{Distribution, Uniform};
 use rand::Rng;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
 impl Dummy<Faker> for Ipv4Addr {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
-        let u = Uniform::new_inclusive(u8::MIN, u8::MAX).expect("u8::MIN <= u8::MAX");
-        Ipv4Addr::new(u.sample(rng), u.sample(rng), u.sample(rng), u.sample(rng))
+        let u = |rng: &mut R| (..).fake_with_rng::<u8, _>(rng);
+        Ipv4Addr::new(u(rng), u(rng), u(rng), u(rng))
     }
 }
 
 impl Dummy<Faker> for Ipv6Addr {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
-        let u = Uniform::new_inclusive(u16::MIN, u16::MAX).expect("u16::MIN <= u16::MAX");
+        let u = |rng: &mut R| (..).fake_with_rng::<u16, _>(rng);
         Ipv6Addr::new(
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
-            u.sample(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
+            u(rng),
         )
     }
 }
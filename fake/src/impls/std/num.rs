@@ -1,10 +1,10 @@
 use crate::{Dummy, Faker};
-use rand::distr::{Distribution, Uniform};
-use rand::Rng;
-use std::num::{
+use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
     NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
 };
+use rand::distr::{Distribution, Uniform};
+use rand::Rng;
 
 macro_rules! signed_faker_impl {
     ($nz_typ: ty, $typ:ty) => {
@@ -1,4 +1,4 @@
-use crate::{faker::boolean::en::Boolean, Dummy, Fake, Faker};
+use crate::{faker::boolean::en::Boolean, Dummy, Fake, Faker, FakerConfig};
 use rand::Rng;
 
 impl<T, U> Dummy<U> for Option<T>
@@ -6,7 +6,11 @@ where
     T: Dummy<U>,
 {
     fn dummy_with_rng<R: Rng + ?Sized>(config: &U, rng: &mut R) -> Self {
-        if Faker.fake_with_rng::<bool, _>(rng) {
+        let some = match FakerConfig::current().none_ratio_percent() {
+            50 => Faker.fake_with_rng::<bool, _>(rng),
+            ratio => Boolean(100 - ratio).fake_with_rng(rng),
+        };
+        if some {
             Some(T::dummy_with_rng(config, rng))
         } else {
             None
@@ -1,11 +1,13 @@
+use crate::stable::{self, Sample, SampleRange};
 use crate::{Dummy, Faker};
+use core::ops;
 use rand::distr::{Distribution, Uniform};
 use rand::Rng;
-use std::ops;
 
 macro_rules! faker_impl {
     ($typ:ty) => {
         impl Dummy<$typ> for $typ {
+            #[cfg(feature = "std")]
             fn dummy(t: &$typ) -> Self {
                 t.clone()
             }
@@ -17,6 +19,9 @@ macro_rules! faker_impl {
 
         impl Dummy<Faker> for $typ {
             fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+                if stable::is_enabled() {
+                    return Sample::any(rng);
+                }
                 rng.random()
             }
         }
@@ -24,6 +29,7 @@ macro_rules! faker_impl {
 }
 
 impl Dummy<usize> for usize {
+    #[cfg(feature = "std")]
     fn dummy(t: &usize) -> Self {
         *t
     }
@@ -35,11 +41,15 @@ impl Dummy<usize> for usize {
 
 impl Dummy<Faker> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return Sample::any(rng);
+        }
         rng.random::<u64>() as usize
     }
 }
 
 impl Dummy<isize> for isize {
+    #[cfg(feature = "std")]
     fn dummy(t: &isize) -> Self {
         *t
     }
@@ -51,6 +61,9 @@ impl Dummy<isize> for isize {
 
 impl Dummy<Faker> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return Sample::any(rng);
+        }
         rng.random::<i64>() as isize
     }
 }
@@ -71,12 +84,18 @@ macro_rules! range_impl {
     ($typ:ident) => {
         impl Dummy<ops::Range<Self>> for $typ {
             fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::Range<Self>, rng: &mut R) -> Self {
+                if stable::is_enabled() {
+                    return SampleRange::below(range.start, range.end, rng);
+                }
                 rng.random_range(range.start..range.end)
             }
         }
 
         impl Dummy<ops::RangeFrom<Self>> for $typ {
             fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeFrom<Self>, rng: &mut R) -> Self {
+                if stable::is_enabled() {
+                    return SampleRange::between(range.start, Self::MAX, rng);
+                }
                 let u = Uniform::new_inclusive(range.start, $typ::MAX).expect("Can sample uniform");
                 u.sample(rng)
             }
@@ -84,6 +103,9 @@ macro_rules! range_impl {
 
         impl Dummy<ops::RangeFull> for $typ {
             fn dummy_with_rng<R: Rng + ?Sized>(_: &ops::RangeFull, rng: &mut R) -> Self {
+                if stable::is_enabled() {
+                    return SampleRange::between(Self::MIN, Self::MAX, rng);
+                }
                 let u = Uniform::new_inclusive($typ::MIN, $typ::MAX).expect("Can sample uniform");
                 u.sample(rng)
             }
@@ -94,6 +116,9 @@ macro_rules! range_impl {
                 range: &ops::RangeInclusive<Self>,
                 rng: &mut R,
             ) -> Self {
+                if stable::is_enabled() {
+                    return SampleRange::between(*range.start(), *range.end(), rng);
+                }
                 let u =
                     Uniform::new_inclusive(range.start(), range.end()).expect("Can sample uniform");
                 u.sample(rng)
@@ -102,6 +127,9 @@ macro_rules! range_impl {
 
         impl Dummy<ops::RangeTo<Self>> for $typ {
             fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeTo<Self>, rng: &mut R) -> Self {
+                if stable::is_enabled() {
+                    return SampleRange::below(Self::MIN, range.end, rng);
+                }
                 rng.random_range($typ::MIN..range.end)
             }
         }
@@ -111,6 +139,9 @@ macro_rules! range_impl {
                 range: &ops::RangeToInclusive<Self>,
                 rng: &mut R,
             ) -> Self {
+                if stable::is_enabled() {
+                    return SampleRange::between(Self::MIN, range.end, rng);
+                }
                 let u = Uniform::new_inclusive($typ::MIN, range.end).expect("Can sample uniform");
                 u.sample(rng)
             }
@@ -120,12 +151,18 @@ macro_rules! range_impl {
 
 impl Dummy<ops::Range<Self>> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::Range<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::below(range.start, range.end, rng);
+        }
         rng.random_range(range.start..range.end)
     }
 }
 
 impl Dummy<ops::RangeFrom<Self>> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeFrom<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(range.start, Self::MAX, rng);
+        }
         let u = Uniform::new_inclusive(range.start as u64, u64::MAX).expect("Can sample uniform");
         u.sample(rng) as usize
     }
@@ -133,6 +170,9 @@ impl Dummy<ops::RangeFrom<Self>> for usize {
 
 impl Dummy<ops::RangeFull> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &ops::RangeFull, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(Self::MIN, Self::MAX, rng);
+        }
         let u = Uniform::new_inclusive(u64::MIN, u64::MAX).expect("Can sample uniform");
         u.sample(rng) as usize
     }
@@ -140,6 +180,9 @@ impl Dummy<ops::RangeFull> for usize {
 
 impl Dummy<ops::RangeInclusive<Self>> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeInclusive<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(*range.start(), *range.end(), rng);
+        }
         let u = Uniform::new_inclusive(*range.start() as u64, *range.end() as u64)
             .expect("Can sample uniform");
         u.sample(rng) as usize
@@ -148,12 +191,18 @@ impl Dummy<ops::RangeInclusive<Self>> for usize {
 
 impl Dummy<ops::RangeTo<Self>> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeTo<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::below(Self::MIN, range.end, rng);
+        }
         rng.random_range(u64::MIN..range.end as u64) as usize
     }
 }
 
 impl Dummy<ops::RangeToInclusive<Self>> for usize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeToInclusive<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(Self::MIN, range.end, rng);
+        }
         let u = Uniform::new_inclusive(u64::MIN, range.end as u64).expect("Can sample uniform");
         u.sample(rng) as usize
     }
@@ -161,12 +210,18 @@ impl Dummy<ops::RangeToInclusive<Self>> for usize {
 
 impl Dummy<ops::Range<Self>> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::Range<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::below(range.start, range.end, rng);
+        }
         rng.random_range(range.start as i64..range.end as i64) as isize
     }
 }
 
 impl Dummy<ops::RangeFrom<Self>> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeFrom<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(range.start, Self::MAX, rng);
+        }
         let u = Uniform::new_inclusive(range.start as i64, i64::MAX).expect("Can sample uniform");
         u.sample(rng) as isize
     }
@@ -174,6 +229,9 @@ impl Dummy<ops::RangeFrom<Self>> for isize {
 
 impl Dummy<ops::RangeFull> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &ops::RangeFull, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(Self::MIN, Self::MAX, rng);
+        }
         let u = Uniform::new_inclusive(i64::MIN, i64::MAX).expect("Can sample uniform");
         u.sample(rng) as isize
     }
@@ -181,6 +239,9 @@ impl Dummy<ops::RangeFull> for isize {
 
 impl Dummy<ops::RangeInclusive<Self>> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeInclusive<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(*range.start(), *range.end(), rng);
+        }
         let u = Uniform::new_inclusive(*range.start() as i64, *range.end() as i64)
             .expect("Can sample uniform");
         u.sample(rng) as isize
@@ -189,12 +250,18 @@ impl Dummy<ops::RangeInclusive<Self>> for isize {
 
 impl Dummy<ops::RangeTo<Self>> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeTo<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::below(Self::MIN, range.end, rng);
+        }
         rng.random_range(i64::MIN..range.end as i64) as isize
     }
 }
 
 impl Dummy<ops::RangeToInclusive<Self>> for isize {
     fn dummy_with_rng<R: Rng + ?Sized>(range: &ops::RangeToInclusive<Self>, rng: &mut R) -> Self {
+        if stable::is_enabled() {
+            return SampleRange::between(Self::MIN, range.end, rng);
+        }
         let u = Uniform::new_inclusive(i64::MIN, range.end as i64).expect("Can sample uniform");
         u.sample(rng) as isize
     }
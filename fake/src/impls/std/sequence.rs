@@ -0,0 +1,228 @@
+use crate::Dummy;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use rand::Rng;
+
+/// Counter faker producing monotonically increasing values.
+///
+/// Each generated value advances the counter by `step`, whatever the type
+/// being generated. The counter is atomic, so a `static` [`Sequence`] keeps
+/// counting across `#[derive(Dummy)]` structs, `fake::vec!` and threads.
+///
+/// Generating a [`String`] uses the optional format template, where `{}` is
+/// replaced by the value and `{:0N}` by the value zero-padded to `N` digits.
+///
+/// Only available on targets with 64-bit atomics.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Dummy, Fake, Faker, Sequence};
+///
+/// static ORDER_IDS: Sequence = Sequence::new(1);
+/// static ORDER_REFS: Sequence = Sequence::with_format(1, 1, "ORD-{:06}");
+///
+/// #[derive(Debug, Dummy)]
+/// struct Order {
+///     #[dummy(faker = "ORDER_IDS")]
+///     id: u64,
+///     #[dummy(faker = "ORDER_REFS")]
+///     reference: String,
+/// }
+///
+/// let orders = fake::vec![Order; 3];
+/// assert_eq!(orders[2].id, 3);
+/// assert_eq!(orders[2].reference, "ORD-000003");
+///
+/// let evens = Sequence::with_step(0, 2);
+/// let v: Vec<u32> = (0..3).map(|_| evens.fake()).collect();
+/// assert_eq!(v, [0, 2, 4]);
+/// ```
+pub struct Sequence {
+    start: u64,
+    step: u64,
+    next: AtomicU64,
+    /// Set once `next` has been produced without a value after it.
+    exhausted: AtomicBool,
+    format: Option<Format>,
+}
+
+impl Sequence {
+    /// Sequence counting up by one from `start`.
+    pub const fn new(start: u64) -> Self {
+        Self::with_step(start, 1)
+    }
+
+    /// Sequence counting up by `step` from `start`.
+    pub const fn with_step(start: u64, step: u64) -> Self {
+        Sequence {
+            start,
+            step,
+            next: AtomicU64::new(start),
+            exhausted: AtomicBool::new(false),
+            format: None,
+        }
+    }
+
+    /// Sequence counting up by `step` from `start`, with `format` as the
+    /// template of generated strings.
+    ///
+    /// # Panics
+    ///
+    /// If `format` has an unclosed `{` or a placeholder other than `{}`,
+    /// `{:N}` and `{:0N}`, at compile time for a `static` or `const`.
+    pub const fn with_format(start: u64, step: u64, format: &'static str) -> Self {
+        Sequence {
+            start,
+            step,
+            next: AtomicU64::new(start),
+            exhausted: AtomicBool::new(false),
+            format: Some(Format::parse(format)),
+        }
+    }
+
+    /// Value the next call will produce, without advancing the counter.
+    ///
+    /// Once the sequence is exhausted, this is the last value it produced.
+    pub fn peek(&self) -> u64 {
+        self.next.load(Ordering::Relaxed)
+    }
+
+    /// Restart the sequence from its start value.
+    pub fn reset(&self) {
+        self.next.store(self.start, Ordering::Relaxed);
+        self.exhausted.store(false, Ordering::Relaxed);
+    }
+
+    /// Advance the counter and return the previous value.
+    ///
+    /// # Panics
+    ///
+    /// When asked for a value past [`u64::MAX`].
+    pub fn next_value(&self) -> u64 {
+        let step = self.step;
+        match self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |next| {
+                next.checked_add(step)
+            }) {
+            Ok(value) => value,
+            // the counter can't move past `value`, hand it out once
+            Err(value) => {
+                if self.exhausted.swap(true, Ordering::Relaxed) {
+                    panic!("sequence overflowed after {}", value);
+                }
+                value
+            }
+        }
+    }
+}
+
+/// Format template of a [`Sequence`], checked when the sequence is built.
+struct Format {
+    template: &'static str,
+    /// Byte offsets of the `{` and `}` of the placeholder, if any.
+    placeholder: Option<(usize, usize)>,
+    width: usize,
+    zero_pad: bool,
+}
+
+impl Format {
+    const fn parse(template: &'static str) -> Self {
+        let bytes = template.as_bytes();
+        let mut open = 0;
+        while open < bytes.len() && bytes[open] != b'{' {
+            open += 1;
+        }
+        if open == bytes.len() {
+            return Format {
+                template,
+                placeholder: None,
+                width: 0,
+                zero_pad: false,
+            };
+        }
+        let mut close = open + 1;
+        while close < bytes.len() && bytes[close] != b'}' {
+            close += 1;
+        }
+        if close == bytes.len() {
+            panic!("sequence format has an unclosed `{{`");
+        }
+
+        let mut width = 0usize;
+        let mut zero_pad = false;
+        if close > open + 1 {
+            if bytes[open + 1] != b':' || close == open + 2 {
+                panic!("sequence format placeholder must be `{{}}`, `{{:N}}` or `{{:0N}}`");
+            }
+            zero_pad = bytes[open + 2] == b'0';
+            let mut i = open + 2;
+            while i < close {
+                let digit = bytes[i];
+                if !digit.is_ascii_digit() {
+                    panic!("sequence format width must be a number");
+                }
+                width = match width.checked_mul(10) {
+                    Some(width) => match width.checked_add((digit - b'0') as usize) {
+                        Some(width) => width,
+                        None => panic!("sequence format width is too large"),
+                    },
+                    None => panic!("sequence format width is too large"),
+                };
+                i += 1;
+            }
+        }
+        Format {
+            template,
+            placeholder: Some((open, close)),
+            width,
+            zero_pad,
+        }
+    }
+
+    fn render(&self, value: u64) -> String {
+        let (open, close) = match self.placeholder {
+            Some(pos) => pos,
+            None => return format!("{}{}", self.template, value),
+        };
+        let value = if self.zero_pad {
+            format!("{:0width$}", value, width = self.width)
+        } else {
+            format!("{:width$}", value, width = self.width)
+        };
+        format!(
+            "{}{}{}",
+            &self.template[..open],
+            value,
+            &self.template[close + 1..]
+        )
+    }
+}
+
+impl Dummy<Sequence> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Sequence, _: &mut R) -> Self {
+        let value = config.next_value();
+        match config.format {
+            Some(ref format) => format.render(value),
+            None => value.to_string(),
+        }
+    }
+}
+
+macro_rules! sequence_impl {
+    ($($typ:ty),*) => {
+        $(
+            impl Dummy<Sequence> for $typ {
+                fn dummy_with_rng<R: Rng + ?Sized>(config: &Sequence, _: &mut R) -> Self {
+                    <$typ>::try_from(config.next_value()).expect("sequence value out of range")
+                }
+            }
+        )*
+    };
+}
+
+sequence_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -1,10 +1,9 @@
-use crate::{Dummy, Fake, Faker};
+use crate::{Dummy, Fake, Faker, FakerConfig};
+use alloc::{string::String, vec::Vec};
+use core::ops;
 use rand::distr::Alphanumeric;
 use rand::seq::IndexedRandom;
 use rand::Rng;
-use std::ops;
-
-const DEFAULT_STR_LEN_RANGE: ops::Range<usize> = 5..20;
 
 impl Dummy<usize> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(len: &usize, rng: &mut R) -> Self {
@@ -17,7 +16,7 @@ impl Dummy<usize> for String {
 
 impl Dummy<Faker> for String {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
-        let len: usize = DEFAULT_STR_LEN_RANGE.fake_with_rng(rng);
+        let len: usize = FakerConfig::current().string_len_range().fake_with_rng(rng);
         len.fake_with_rng(rng)
     }
 }
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use core::time::Duration;
 
 use crate::{Dummy, Fake, Faker};
 use rand::Rng;
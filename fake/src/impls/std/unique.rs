@@ -0,0 +1,169 @@
+use crate::seed::FakeRng;
+use crate::utils::WrappedVal;
+use crate::Dummy;
+use rand::Rng;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+const DEFAULT_RETRIES: usize = 100;
+
+/// Faker wrapper that never produces the same value twice.
+///
+/// It works for any output type `T` implementing `Dummy<F>`, [`Hash`], [`Eq`]
+/// and [`Clone`]: a copy of every value generated is kept to tell the new
+/// ones apart. See [`WrappedVal`] for the types generated directly. Clones
+/// share the set of values already seen, which makes a [`Unique`] usable as a
+/// session across several generators.
+///
+/// When the wrapped faker keeps returning known values for more than the
+/// configured number of retries, generating [`Result<T, UniqueError>`]
+/// returns an error while generating `T` directly panics.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Fake, Unique, UniqueError};
+/// use fake::faker::internet::en::SafeEmail;
+///
+/// let emails = Unique::new(SafeEmail());
+/// let a: String = emails.fake();
+/// let b: String = emails.fake();
+/// assert_ne!(a, b);
+///
+/// // the domain of `0..3` runs out after three values
+/// let ids = Unique::new(0..3).retries(1_000);
+/// let mut values: Vec<u8> = (0..3).map(|_| ids.fake()).collect();
+/// values.sort();
+/// assert_eq!(values, [0, 1, 2]);
+/// assert!(ids.fake::<Result<u8, UniqueError>>().is_err());
+/// ```
+pub struct Unique<F, T> {
+    faker: F,
+    retries: usize,
+    seen: Arc<Mutex<HashSet<T>>>,
+}
+
+impl<F: Clone, T> Clone for Unique<F, T> {
+    fn clone(&self) -> Self {
+        Unique {
+            faker: self.faker.clone(),
+            retries: self.retries,
+            seen: Arc::clone(&self.seen),
+        }
+    }
+}
+
+impl<F, T> Unique<F, T> {
+    /// Wrap `faker`, with no value seen yet.
+    pub fn new(faker: F) -> Self {
+        Unique {
+            faker,
+            retries: DEFAULT_RETRIES,
+            seen: Arc::default(),
+        }
+    }
+
+    /// Number of consecutive duplicates tolerated before giving up.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Number of distinct values generated so far.
+    pub fn len(&self) -> usize {
+        self.seen.lock().unwrap().len()
+    }
+
+    /// Whether no value was generated yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget every value generated so far.
+    pub fn reset(&self) {
+        self.seen.lock().unwrap().clear();
+    }
+
+    /// Generate a value not seen before.
+    pub fn try_fake_with_rng<R>(&self, rng: &mut R) -> Result<T, UniqueError>
+    where
+        T: Dummy<F> + Hash + Eq + Clone,
+        R: Rng + ?Sized,
+    {
+        for _ in 0..=self.retries {
+            let value = T::dummy_with_rng(&self.faker, rng);
+            if self.seen.lock().unwrap().insert(value.clone()) {
+                return Ok(value);
+            }
+        }
+        Err(UniqueError {
+            retries: self.retries,
+            generated: self.len(),
+        })
+    }
+
+    /// Generate a value not seen before with the thread-local RNG.
+    pub fn try_fake(&self) -> Result<T, UniqueError>
+    where
+        T: Dummy<F> + Hash + Eq + Clone,
+    {
+        self.try_fake_with_rng(&mut FakeRng::new())
+    }
+}
+
+/// Error returned when a [`Unique`] faker runs out of new values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniqueError {
+    pub retries: usize,
+    pub generated: usize,
+}
+
+impl fmt::Display for UniqueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unique faker exhausted: no new value after {} retries ({} distinct values generated)",
+            self.retries, self.generated
+        )
+    }
+}
+
+impl std::error::Error for UniqueError {}
+
+impl<F, T> Dummy<Unique<F, T>> for Result<T, UniqueError>
+where
+    T: Dummy<F> + Hash + Eq + Clone,
+{
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Unique<F, T>, rng: &mut R) -> Self {
+        config.try_fake_with_rng(rng)
+    }
+}
+
+macro_rules! unique_impl {
+    ([$($gen:tt)*] $typ:ty => $inner:ty, $wrap:expr) => {
+        impl<$($gen)* F> Dummy<Unique<F, $inner>> for $typ
+        where
+            $inner: Dummy<F> + Hash + Eq + Clone,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &Unique<F, $inner>, rng: &mut R) -> Self {
+                $wrap(
+                    config
+                        .try_fake_with_rng(rng)
+                        .unwrap_or_else(|e| panic!("{}", e)),
+                )
+            }
+        }
+    };
+    ($($typ:ty),*) => {
+        $(
+            unique_impl!([] $typ => $typ, core::convert::identity);
+        )*
+    };
+}
+
+unique_impl!(String, bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+unique_impl!(['a,] &'a str => &'a str, core::convert::identity);
+unique_impl!([X,] Vec<X> => Vec<X>, core::convert::identity);
+unique_impl!([X,] WrappedVal<X> => X, WrappedVal::new);
@@ -2,6 +2,11 @@
 //!
 //! # Feature flags
 //!
+//! - `std` (default): Use the standard library for the thread-local RNG behind
+//!   [`Fake::fake`], and for impls of paths, network addresses, hash
+//!   collections and locks. Without it the crate is `no_std` and only needs
+//!   `alloc`, values are generated with [`Fake::fake_with_rng`]. The
+//!   integrations below require `std`.
 //! - `derive`: Enable `#[derive(Dummy)]` macro
 //! - `bigdecimal`: [bigdecimal](https://docs.rs/bigdecimal) integration
 //! - `bson_oid`: [bson](https://docs.rs/bson) integration
@@ -10,6 +15,9 @@
 //! - `geo`: [geo-types](https://docs.rs/geo-types) integration
 //! - `glam`: [glam](https://docs.rs/glam) integration
 //! - `http`: [http](https://docs.rs/http) integration
+//! - `proptest`: [proptest](https://docs.rs/proptest) strategies for any faker
+//! - `rayon`: parallel bulk generation with `par_generate`
+//! - `regex`: fake strings matching a regular expression, see `Regex`
 //! - `rust-decimal`: [rust_decimal](https://docs.rs/rust_decimal) integration
 //! - `time`: [time](https://docs.rs/time) integration
 //! - `ulid`: [ulid](https://docs.rs/ulid) integration
@@ -53,6 +61,11 @@
 //! let name: String = Name(ZH_TW).fake();
 //! println!("name {:?}", name);
 //!
+//! // locale picked at runtime, e.g. from a config file
+//! let locale: Locale = "fr_fr".parse().unwrap();
+//! let name: String = Name(locale).fake();
+//! println!("name {:?}", name);
+//!
 //! // using convenient function without providing locale
 //! use fake::faker::lorem::en::*;
 //! let words: Vec<String> = Words(3..5).fake();
@@ -79,6 +92,11 @@
 //!     println!("value from fixed seed {}", v);
 //! }
 //!
+//! // seed the RNG used by `fake()` on this thread, see `seed` module for more
+//! fake::set_seed(42);
+//! let v: usize = Faker.fake();
+//! println!("value from global seed {}", v);
+//!
 //! # #[cfg(feature = "always-true-rng")] {
 //! // Use an always true RNG so that optional types are always `Some` values. (Requires
 //! // always-true-rng feature).
@@ -91,6 +109,9 @@
 
 // Enable `doc_cfg` feature for `docs.rs`
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub use rand;
 pub use rand::Rng;
@@ -154,9 +175,11 @@ pub trait Dummy<T>: Sized {
     /// Generate a dummy value for a type.
     ///
     /// This can be left as a blanket implemented most of the time since it
-    /// uses [`Dummy::dummy_with_rng`] under the hood.
+    /// uses [`Dummy::dummy_with_rng`] under the hood, with the thread-local
+    /// RNG described in [`seed`].
+    #[cfg(feature = "std")]
     fn dummy(config: &T) -> Self {
-        let mut r = rand::rng();
+        let mut r = seed::FakeRng::new();
         Dummy::<T>::dummy_with_rng(config, &mut r)
     }
 
@@ -167,6 +190,7 @@ pub trait Dummy<T>: Sized {
 
 mod private {
     pub trait FakeBase<T>: Sized {
+        #[cfg(feature = "std")]
         fn _fake(&self) -> T;
         fn _fake_with_rng<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> T;
     }
@@ -175,6 +199,7 @@ mod private {
     where
         U: crate::Dummy<T>,
     {
+        #[cfg(feature = "std")]
         fn _fake(&self) -> U {
             U::dummy(self)
         }
@@ -206,8 +231,12 @@ mod private {
 /// assert_eq!(10.fake::<String>().len(), 10);
 /// let a: [[u8; 2]; 3] = (1..10).fake();
 /// let b: Option<Option<usize>> = (1..10).fake();
+///
+/// // lazy, infinite stream of values
+/// let c: Vec<u8> = (1..10).fake_iter().take(5).collect();
 /// ```
 pub trait Fake: Sized {
+    #[cfg(feature = "std")]
     #[inline]
     fn fake<U>(&self) -> U
     where
@@ -223,49 +252,150 @@ pub trait Fake: Sized {
     {
         self._fake_with_rng(rng)
     }
+
+    /// Infinite iterator of fake values, see [`FakeIter`].
+    #[cfg(feature = "std")]
+    #[inline]
+    fn fake_iter<U>(&self) -> FakeIter<'_, Self, U, seed::FakeRng>
+    where
+        Self: private::FakeBase<U>,
+    {
+        FakeIter::new(self, seed::FakeRng::new())
+    }
+
+    /// Infinite iterator of fake values drawn from `rng`, see [`FakeIter`].
+    #[inline]
+    fn fake_iter_with_rng<'a, U, R: Rng + ?Sized>(
+        &'a self,
+        rng: &'a mut R,
+    ) -> FakeIter<'a, Self, U, &'a mut R>
+    where
+        Self: private::FakeBase<U>,
+    {
+        FakeIter::new(self, rng)
+    }
+
+    /// Iterator over every value this config can generate, see [`Enumerate`].
+    #[inline]
+    fn fake_all<U: Enumerate<Self>>(&self) -> U::Iter {
+        U::enumerate(self)
+    }
 }
 impl<T> Fake for T {}
 
-#[cfg(feature = "geo")]
-#[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
-fn unique<U: Dummy<Faker> + PartialEq, R: Rng + ?Sized>(rng: &mut R, len: usize) -> Vec<U> {
-    let mut set = Vec::<U>::new();
-    unique_append(&mut set, rng, len);
-    set
+/// Lazy, infinite iterator of fake values.
+///
+/// Created by [`Fake::fake_iter`] and [`Fake::fake_iter_with_rng`]. Values
+/// are only generated when requested, so it composes with adapters like
+/// `take`, `zip` and `filter` without materialising anything.
+///
+/// # Examples
+///
+/// ```
+/// use fake::{Fake, Faker};
+/// use fake::faker::name::en::Name;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let rows: Vec<(u32, String)> = (1..100)
+///     .fake_iter_with_rng::<u32, _>(&mut rng)
+///     .filter(|id| id % 2 == 0)
+///     .zip(Name().fake_iter())
+///     .take(10)
+///     .collect();
+/// assert_eq!(rows.len(), 10);
+/// ```
+pub struct FakeIter<'a, T, U, R> {
+    config: &'a T,
+    rng: R,
+    _output: core::marker::PhantomData<fn() -> U>,
 }
 
-#[cfg(feature = "geo")]
-#[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
-fn unique_append<U: Dummy<Faker> + PartialEq, R: Rng + ?Sized>(
-    set: &mut Vec<U>,
-    rng: &mut R,
-    len: usize,
-) {
-    while set.len() != len {
-        let new_item: U = Faker.fake_with_rng(rng);
-        let mut found = false;
-        for item in &mut *set {
-            if *item == new_item {
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            set.push(new_item);
+impl<'a, T, U, R> FakeIter<'a, T, U, R> {
+    fn new(config: &'a T, rng: R) -> Self {
+        FakeIter {
+            config,
+            rng,
+            _output: core::marker::PhantomData,
         }
     }
 }
+
+impl<T, U, R> Iterator for FakeIter<'_, T, U, R>
+where
+    T: private::FakeBase<U>,
+    R: Rng,
+{
+    type Item = U;
+
+    #[inline]
+    fn next(&mut self) -> Option<U> {
+        Some(self.config._fake_with_rng(&mut self.rng))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T, U, R> core::iter::FusedIterator for FakeIter<'_, T, U, R>
+where
+    T: private::FakeBase<U>,
+    R: Rng,
+{
+}
+
+pub mod bulk;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use bulk::par_generate;
+pub mod combinator;
+pub mod config;
+pub use config::FakerConfig;
+pub mod distribution;
+pub mod enumerate;
+pub use enumerate::Enumerate;
+pub mod pool;
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest;
+pub mod registry;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod seed;
+#[cfg(feature = "std")]
+pub use seed::{clear_seed, current_seed, seeded, set_random_seed, set_seed};
+pub mod stable;
+pub mod template;
+pub use template::Template;
+mod write;
+pub use write::FakeWrite;
+
 #[macro_use]
 mod impls;
 pub use impls::std::option::{Opt, Optional};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use impls::std::path::PathFaker;
 pub use impls::std::result::ResultFaker;
+#[cfg(target_has_atomic = "64")]
+#[cfg_attr(docsrs, doc(cfg(target_has_atomic = "64")))]
+pub use impls::std::sequence::Sequence;
 pub use impls::std::string::StringFaker;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use impls::std::unique::{Unique, UniqueError};
 
 #[cfg(feature = "geo")]
 #[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
 pub use impls::geo;
 
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub use impls::regex::{Regex, RegexFaker};
+
 #[cfg(feature = "ulid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ulid")))]
 pub use impls::ulid;
@@ -340,6 +470,29 @@ pub mod locales;
 /// 2. `expr` key can be used to provide a rust expression as a fixed value.
 /// 3. `default` key sets the value to the types [`Default`] implementation.
 ///
+/// On the type itself, `#[dummy(stable_fields)]` generates each field from its own RNG, seeded
+/// with one `u64` drawn from the parent RNG and the field name (see [`stable`]). A field then
+/// keeps its values when other fields are added, removed or reordered.
+///
+/// ```
+/// use fake::{Dummy, Fake, Faker};
+/// use fake::faker::name::en::Name;
+///
+/// #[derive(Dummy)]
+/// #[dummy(stable_fields)]
+/// pub struct Customer {
+///     #[dummy(faker = "Name()")]
+///     name: String,
+///     age: u8,
+/// }
+///
+/// let c: Customer = Faker.fake();
+/// ```
+///
+/// The struct is a [`MixedRecord`](locales::MixedRecord): every field generated with the
+/// same [`Mixed`](locales::Mixed) weights gets the same locale, unless it is marked
+/// `#[dummy(mixed_per_field)]`.
+///
 /// # Examples
 ///
 /// A simple example for deriving [`Dummy`] on a struct:
@@ -458,4 +611,48 @@ pub mod locales;
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use dummy::Dummy;
 
+/// Attribute macro turning a function into a test run with a seeded RNG, see
+/// [`seed::run_seeded`].
+///
+/// The function may take the RNG as its only argument, and may return a
+/// `Result`, an `Err` failing the test like a panic. When the test fails, the
+/// seed of the failing run is printed, and setting the `FAKE_SEED`
+/// environment variable to it replays the run.
+///
+/// # Attributes
+///
+/// 1. `runs` runs the test with that many different seeds, one by default.
+/// 2. `crate_name` sets the path of this crate, like for [`Dummy`].
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::internet::en::SafeEmail;
+/// use fake::{Fake, Faker};
+/// use rand::Rng;
+///
+/// #[fake::test(runs = 100)]
+/// fn email_has_at(rng: &mut impl Rng) {
+///     let email: String = SafeEmail().fake_with_rng(rng);
+///     assert!(email.contains('@'));
+/// }
+///
+/// #[fake::test]
+/// fn without_rng() {
+///     // the RNG-less methods are seeded too
+///     let id: u64 = Faker.fake();
+///     let _ = id;
+/// }
+/// ```
+#[cfg(all(feature = "derive", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "derive", feature = "std"))))]
+pub use dummy::test;
+
+/// Derive macro generating an impl of the trait [`Enumerate`] for
+/// [`Faker`], listing the variants of an enum without fields. Variants with
+/// `#[dummy(skip)]` are left out, like for [`Dummy`][macro@Dummy].
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use dummy::Enumerate;
+
 pub mod utils;
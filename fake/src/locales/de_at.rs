@@ -6,14 +6,17 @@ use crate::{
         impls::address::CityNameGenFn,
         name::raw::{LastName, Name},
     },
-    locales::Data,
+    locales::DE_DE,
     Fake,
 };
+use alloc::string::String;
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct AT;
+pub struct DE_AT;
+
+inherit_data! {
+    DE_AT: DE_DE;
 
-impl Data for AT {
     const NAME_FIRST_NAME: &'static [&'static str] = &[
         // Common Austrian male names
         "Michael", "Thomas", "Andreas", "Christian", "Stefan", "Martin", "Daniel", "Josef",
@@ -28,22 +31,20 @@ impl Data for AT {
         "Magdalena", "Sonja", "Susanne", "Bettina", "Nicole", "Daniela", "Laura", "Johanna",
         "Ursula", "Angelika", "Doris", "Jasmin", "Helga", "Silvia", "Carmen", "Ingrid"
     ];
-    
+
     const NAME_LAST_NAME: &'static [&'static str] = &[
         // Common Austrian surnames
-        "Gruber", "Huber", "Wagner", "Müller", "Pichler", "Steiner", "Moser", "Mayer", 
-        "Hofer", "Leitner", "Berger", "Fuchs", "Eder", "Fischer", "Schmid", "Winkler", 
+        "Gruber", "Huber", "Wagner", "Müller", "Pichler", "Steiner", "Moser", "Mayer",
+        "Hofer", "Leitner", "Berger", "Fuchs", "Eder", "Fischer", "Schmid", "Winkler",
         "Weber", "Schwarz", "Maier", "Schneider", "Reiter", "Bauer", "Brunner", "Wallner",
         "Holzer", "Aigner", "Ebner", "Koller", "Lehner", "Winter", "Haas", "Auer", "Graf",
         "Baumgartner", "Mayr", "Egger", "Wolf", "Lang", "Zimmermann", "Koch", "Hofmann",
         "Pfeffer", "Kern", "Schuster", "Weiss", "Haider", "Stadler", "Strasser", "Riegler",
         "Huemer", "Bruckner", "Zach", "Kurz", "Strobl", "Schober", "Wieser", "Kainz", "Rauch"
     ];
-    
-    const NAME_TITLE: &'static [&'static str] = &["Frau", "Herr"];
 
     const ADDRESS_STATE: &'static [&'static str] = &[
-        "Wien", "Niederösterreich", "Oberösterreich", "Steiermark", "Tirol", 
+        "Wien", "Niederösterreich", "Oberösterreich", "Steiermark", "Tirol",
         "Kärnten", "Salzburg", "Vorarlberg", "Burgenland"
     ];
 
@@ -52,7 +53,7 @@ impl Data for AT {
     ];
 
     const ADDRESS_COUNTRY: &'static [&'static str] = &["Österreich", "Austria"];
-    
+
     const ADDRESS_CITY_SUFFIX: &'static [&'static str] = &[
         "dorf", "stadt", "kirchen", "bach", "berg", "burg", "hausen", "hofen", "brunn", "feld"
     ];
@@ -60,29 +61,35 @@ impl Data for AT {
     const ADDRESS_CITY_PREFIX: &'static [&'static str] = &[
         "Sankt", "St.", "Alt", "Neu", "Bad", "Ober", "Unter", "Groß", "Klein", "Maria"
     ];
-    
+
     const ADDRESS_ZIP_FORMATS: &'static [&'static str] = &["####"];
-    
+
     const ADDRESS_STREET_SUFFIX: &'static [&'static str] = &[
         "straße", "gasse", "platz", "weg", "allee", "ring", "promenade", "zeile"
     ];
-    
+
     const ADDRESS_SECONDARY_ADDR_TYPE: &'static [&'static str] =
         &["Stiege", "Stock", "Tür", "Top", "Eingang"];
 
-    const ADDRESS_STREET_TPL: &'static str = "{StreetName}{StreetSuffix}";
-
     const COMPANY_SUFFIX: &'static [&'static str] = &[
         "GmbH", "AG", "OG", "KG", "GmbH & Co KG", "e.U.", "GesmbH", "& Söhne", "& Co."
     ];
 }
 
-impl CityNameGenFn for AT {
+impl CityNameGenFn for DE_AT {
     fn gen<R: Rng + ?Sized>(c: &CityName<Self>, rng: &mut R) -> String {
         // Austrian rivers and lakes
         const WATERS: [&str; 10] = [
-            "(Donau)", "(Mur)", "(Drau)", "(Inn)", "(Enns)", "(Salzach)", 
-            "(Neusiedler See)", "(Wörthersee)", "(Attersee)", "(Traunsee)"
+            "(Donau)",
+            "(Mur)",
+            "(Drau)",
+            "(Inn)",
+            "(Enns)",
+            "(Salzach)",
+            "(Neusiedler See)",
+            "(Wörthersee)",
+            "(Attersee)",
+            "(Traunsee)",
         ];
 
         // Common formats for Austrian city names
@@ -139,7 +146,10 @@ impl CityNameGenFn for AT {
                     "{CityName}",
                     Name(c.0).fake_with_rng::<String, _>(rng).as_ref(),
                 )
-                .replace("{Water}", WATERS[rng.random_range(0..WATERS.len())]),
+                .replace(
+                    "{Water}",
+                    WATERS[(0..WATERS.len()).fake_with_rng::<usize, _>(rng)],
+                ),
             _ => ADDRESS_CITY_WITHOUT_PREFIX
                 .replace(
                     "{CityName}",
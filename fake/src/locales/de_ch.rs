@@ -6,18 +6,21 @@ use crate::{
         impls::address::CityNameGenFn,
         name::raw::{LastName, Name},
     },
-    locales::Data,
+    locales::DE_DE,
     Fake,
 };
+use alloc::string::String;
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct CH;
+pub struct DE_CH;
+
+inherit_data! {
+    DE_CH: DE_DE;
 
-impl Data for CH {
     const NAME_FIRST_NAME: &'static [&'static str] = &[
         // Common Swiss male names
-        "Michael", "Thomas", "Daniel", "Christian", "Peter", "David", "Andreas", "Markus", 
-        "Stefan", "Martin", "Hans", "Beat", "Marcel", "Patrick", "Simon", "Urs", "Marco", 
+        "Michael", "Thomas", "Daniel", "Christian", "Peter", "David", "Andreas", "Markus",
+        "Stefan", "Martin", "Hans", "Beat", "Marcel", "Patrick", "Simon", "Urs", "Marco",
         "Reto", "Bruno", "René", "Adrian", "Philipp", "Matthias", "Roger", "Kurt", "Walter",
         "Pascal", "Christoph", "Lukas", "Samuel", "Felix", "Marc", "Jürg", "Roland", "Oliver",
         // Common Swiss female names
@@ -27,25 +30,23 @@ impl Data for CH {
         "Lisa", "Martina", "Brigitte", "Heidi", "Verena", "Doris", "Christina", "Marianne",
         "Jasmin", "Kathrin", "Corinne", "Rebecca", "Stefanie", "Michelle", "Margrit"
     ];
-    
+
     const NAME_LAST_NAME: &'static [&'static str] = &[
         // Common Swiss surnames
-        "Müller", "Schmid", "Keller", "Weber", "Huber", "Meyer", "Schneider", "Steiner", 
+        "Müller", "Schmid", "Keller", "Weber", "Huber", "Meyer", "Schneider", "Steiner",
         "Fischer", "Baumann", "Brunner", "Gerber", "Widmer", "Baumgartner", "Zimmermann",
-        "Moser", "Meier", "Wyss", "Schmid", "Suter", "Bucher", "Roth", "Graf", "Hofmann",
-        "Frei", "Lüthi", "Koch", "Marti", "Zbinden", "Egger", "Hofer", "Berger", "Egli",
-        "Hess", "Christen", "Schwarz", "Frey", "Arnold", "Vogel", "Kaufmann", "Peter",
-        "Bachmann", "Bühler", "Wagner", "Blaser", "Studer", "Kohler", "Zürcher", "Siegenthaler",
-        "Bosshard", "Schweizer", "Bühler", "Tanner", "Pfister", "Flückiger", "Ammann",
-        "Kälin", "Stalder", "Bieri", "Stucki"
+        "Moser", "Meier", "Wyss", "Suter", "Bucher", "Roth", "Graf", "Hofmann", "Frei",
+        "Lüthi", "Koch", "Marti", "Zbinden", "Egger", "Hofer", "Berger", "Egli", "Hess",
+        "Christen", "Schwarz", "Frey", "Arnold", "Vogel", "Kaufmann", "Peter", "Bachmann",
+        "Bühler", "Wagner", "Blaser", "Studer", "Kohler", "Zürcher", "Siegenthaler",
+        "Bosshard", "Schweizer", "Tanner", "Pfister", "Flückiger", "Ammann", "Kälin",
+        "Stalder", "Bieri", "Stucki"
     ];
-    
-    const NAME_TITLE: &'static [&'static str] = &["Frau", "Herr"];
 
     const ADDRESS_STATE: &'static [&'static str] = &[
         "Zürich", "Bern", "Luzern", "Uri", "Schwyz", "Obwalden", "Nidwalden", "Glarus",
         "Zug", "Freiburg", "Solothurn", "Basel-Stadt", "Basel-Landschaft", "Schaffhausen",
-        "Appenzell Ausserrhoden", "Appenzell Innerrhoden", "St. Gallen", "Graubünden", 
+        "Appenzell Ausserrhoden", "Appenzell Innerrhoden", "St. Gallen", "Graubünden",
         "Aargau", "Thurgau", "Tessin", "Waadt", "Wallis", "Neuenburg", "Genf", "Jura"
     ];
 
@@ -55,37 +56,41 @@ impl Data for CH {
     ];
 
     const ADDRESS_COUNTRY: &'static [&'static str] = &["Schweiz", "Switzerland", "Suisse", "Svizzera"];
-    
+
     const ADDRESS_CITY_SUFFIX: &'static [&'static str] = &[
-        "berg", "burg", "wil", "dorf", "bach", "tal", "ingen", "ikon", "wil", "ach", "kon"
+        "berg", "burg", "wil", "dorf", "bach", "tal", "ingen", "ikon", "ach", "kon"
     ];
 
     const ADDRESS_CITY_PREFIX: &'static [&'static str] = &[
         "Ober", "Unter", "Alt", "Neu", "Gross", "Klein", "Sankt", "St."
     ];
-    
+
     const ADDRESS_ZIP_FORMATS: &'static [&'static str] = &["####"];
-    
+
     const ADDRESS_STREET_SUFFIX: &'static [&'static str] = &[
         "strasse", "weg", "gasse", "platz", "allee", "steig", "halde", "rain", "matt"
     ];
-    
+
     const ADDRESS_SECONDARY_ADDR_TYPE: &'static [&'static str] =
         &["Gebäude", "Stock", "Etage", "Eingang"];
 
-    const ADDRESS_STREET_TPL: &'static str = "{StreetName}{StreetSuffix}";
-
     const COMPANY_SUFFIX: &'static [&'static str] = &[
         "AG", "GmbH", "Sarl", "SA", "& Co.", "AG & Co. KG", "und Partner", "& Söhne", "& Cie"
     ];
 }
 
-impl CityNameGenFn for CH {
+impl CityNameGenFn for DE_CH {
     fn gen<R: Rng + ?Sized>(c: &CityName<Self>, rng: &mut R) -> String {
         // Swiss lakes
         const LAKES: [&str; 8] = [
-            "(See)", "(Léman)", "(Vierwaldstättersee)", "(Zürichsee)", "(Thunersee)", 
-            "(Brienzersee)", "(Bodensee)", "(Walensee)"
+            "(See)",
+            "(Léman)",
+            "(Vierwaldstättersee)",
+            "(Zürichsee)",
+            "(Thunersee)",
+            "(Brienzersee)",
+            "(Bodensee)",
+            "(Walensee)",
         ];
 
         // Common formats for Swiss city names
@@ -142,7 +147,10 @@ impl CityNameGenFn for CH {
                     "{CityName}",
                     Name(c.0).fake_with_rng::<String, _>(rng).as_ref(),
                 )
-                .replace("{Lake}", LAKES[rng.random_range(0..LAKES.len())]),
+                .replace(
+                    "{Lake}",
+                    LAKES[(0..LAKES.len()).fake_with_rng::<usize, _>(rng)],
+                ),
             _ => ADDRESS_CITY_WITHOUT_PREFIX
                 .replace(
                     "{CityName}",
@@ -9,6 +9,7 @@ use crate::{
     locales::Data,
     Fake,
 };
+use alloc::string::String;
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
 pub struct DE_DE;
@@ -35,7 +36,6 @@ impl Data for DE_DE {
         "Inês",
         "Paddy",
         "Åsa",
-        "Maria",
         "Ursula", // 50 most common female first names
         "Monika",
         "Petra",
@@ -46,7 +46,6 @@ impl Data for DE_DE {
         "Karin",
         "Brigitte",
         "Ingrid",
-        "Erika",
         "Andrea",
         "Gisela",
         "Claudia",
@@ -54,7 +53,6 @@ impl Data for DE_DE {
         "Gabriele",
         "Christa",
         "Christine",
-        "Hildegard",
         "Anna",
         "Birgit",
         "Barbara",
@@ -97,7 +95,6 @@ impl Data for DE_DE {
         "Heinz",
         "Gerhard",
         "Andreas",
-        "Hans",
         "Josef",
         "Günter",
         "Dieter",
@@ -105,7 +102,6 @@ impl Data for DE_DE {
         "Walter",
         "Frank",
         "Bernd",
-        "Karl",
         "Herbert",
         "Franz",
         "Martin",
@@ -212,7 +208,6 @@ impl Data for DE_DE {
         "Winkler",
         "Lorenz",
         "Baumann",
-        "Schuster",
         "Kraus",
         "Böhm",
         "Simon",
@@ -284,7 +279,7 @@ impl Data for DE_DE {
     ];
 
     const ADDRESS_CITY_PREFIX: &'static [&'static str] = &[
-        "Alt", "Bad", "Groß", "Hohen", "Klein", "Neu", "Alt", "Ober", "Unter",
+        "Alt", "Bad", "Groß", "Hohen", "Klein", "Neu", "Ober", "Unter",
     ];
     const ADDRESS_ZIP_FORMATS: &'static [&'static str] = &["#####"];
     const ADDRESS_STREET_SUFFIX: &'static [&'static str] = &[
@@ -380,7 +375,10 @@ impl CityNameGenFn for DE_DE {
                     "{CitySuffix}",
                     CitySuffix(c.0).fake_with_rng::<&str, _>(rng),
                 )
-                .replace("{River}", RIVERS[rng.random_range(0..RIVERS.len())]),
+                .replace(
+                    "{River}",
+                    RIVERS[(0..RIVERS.len()).fake_with_rng::<usize, _>(rng)],
+                ),
             _ => ADDRESS_CITY_WITHOUT_PREFIX
                 .replace(
                     "{CityName}",
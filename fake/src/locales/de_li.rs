@@ -6,14 +6,17 @@ use crate::{
         impls::address::CityNameGenFn,
         name::raw::{LastName, Name},
     },
-    locales::Data,
+    locales::DE_DE,
     Fake,
 };
+use alloc::string::String;
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct LI;
+pub struct DE_LI;
+
+inherit_data! {
+    DE_LI: DE_DE;
 
-impl Data for LI {
     const NAME_FIRST_NAME: &'static [&'static str] = &[
         // Common Liechtenstein male names (German-influenced)
         "Michael", "Thomas", "Peter", "Andreas", "Martin", "Josef", "Daniel", "Christian",
@@ -26,7 +29,7 @@ impl Data for LI {
         "Barbara", "Petra", "Monika", "Melanie", "Karin", "Laura", "Susanne", "Daniela",
         "Verena", "Stefanie", "Birgit", "Magdalena", "Bettina", "Sonja", "Ursula", "Silvia"
     ];
-    
+
     const NAME_LAST_NAME: &'static [&'static str] = &[
         // Common Liechtenstein surnames
         "Marxer", "Frick", "Wenaweser", "Beck", "Büchel", "Frommelt", "Kindle", "Näscher",
@@ -36,11 +39,9 @@ impl Data for LI {
         "Sprenger", "Negele", "Hilti", "Sele", "Batliner", "Götz", "Müller", "Wohlwend",
         "Erne", "Jehle", "Wachter", "Nutt", "Feger", "Kaufmann", "Ritter", "Marogg", "Ott"
     ];
-    
-    const NAME_TITLE: &'static [&'static str] = &["Frau", "Herr"];
 
     const ADDRESS_STATE: &'static [&'static str] = &[
-        "Vaduz", "Schaan", "Balzers", "Triesen", "Eschen", "Mauren", "Triesenberg", 
+        "Vaduz", "Schaan", "Balzers", "Triesen", "Eschen", "Mauren", "Triesenberg",
         "Ruggell", "Gamprin", "Schellenberg", "Planken"
     ];
 
@@ -49,7 +50,7 @@ impl Data for LI {
     ];
 
     const ADDRESS_COUNTRY: &'static [&'static str] = &["Liechtenstein", "Fürstentum Liechtenstein"];
-    
+
     const ADDRESS_CITY_SUFFIX: &'static [&'static str] = &[
         "bach", "berg", "dorf", "feld", "hof", "tal", "wald"
     ];
@@ -57,29 +58,25 @@ impl Data for LI {
     const ADDRESS_CITY_PREFIX: &'static [&'static str] = &[
         "Ober", "Unter", "Alt", "Neu", "Sankt", "St."
     ];
-    
+
     const ADDRESS_ZIP_FORMATS: &'static [&'static str] = &["####"];
-    
+
     const ADDRESS_STREET_SUFFIX: &'static [&'static str] = &[
         "strasse", "gasse", "weg", "platz", "allee", "steig", "ring"
     ];
-    
+
     const ADDRESS_SECONDARY_ADDR_TYPE: &'static [&'static str] =
         &["Haus", "Stock", "Eingang"];
 
-    const ADDRESS_STREET_TPL: &'static str = "{StreetName}{StreetSuffix}";
-
     const COMPANY_SUFFIX: &'static [&'static str] = &[
         "AG", "GmbH", "Anstalt", "Stiftung", "Foundation", "Establishment", "Trust reg."
     ];
 }
 
-impl CityNameGenFn for LI {
+impl CityNameGenFn for DE_LI {
     fn gen<R: Rng + ?Sized>(c: &CityName<Self>, rng: &mut R) -> String {
         // Liechtenstein regions and landmarks
-        const LOCATIONS: [&str; 6] = [
-            "(Rhein)", "(Eschnerberg)"
-        ];
+        const LOCATIONS: [&str; 2] = ["(Rhein)", "(Eschnerberg)"];
 
         // Common formats for Liechtenstein city names (limited since Liechtenstein has only 11 municipalities)
         const ADDRESS_CITY_WITHOUT_PREFIX: &str = "{CityName}{CitySuffix}";
@@ -121,7 +118,10 @@ impl CityNameGenFn for LI {
                     "{CityName}",
                     Name(c.0).fake_with_rng::<String, _>(rng).as_ref(),
                 )
-                .replace("{Location}", LOCATIONS[rng.random_range(0..LOCATIONS.len())]),
+                .replace(
+                    "{Location}",
+                    LOCATIONS[(0..LOCATIONS.len()).fake_with_rng::<usize, _>(rng)],
+                ),
             _ => ADDRESS_CITY_WITHOUT_PREFIX
                 .replace(
                     "{CityName}",
@@ -1,9 +1,14 @@
-use crate::{faker::impls::address::CityNameGenFn, locales::Data};
+use crate::{
+    faker::impls::address::CityNameGenFn,
+    locales::{Data, Locale},
+};
 
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
 pub struct EN;
 
-impl Data for EN {}
+impl Data for EN {
+    const PARENT: Option<Locale> = None;
+}
 
 impl CityNameGenFn for EN {}
@@ -0,0 +1,351 @@
+use super::{with_locale, Locale, LocaleTables, ParseLocaleError, Tables};
+use crate::faker::address::raw::CityName;
+use crate::faker::impls::address::{self, CityNameGenFn};
+use crate::Fake;
+use alloc::string::{String, ToString};
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use rand::Rng;
+
+/// Locale whose tables are loaded at runtime from a TOML or JSON document,
+/// e.g. for in-house word lists, with the `toml` or `serde_json` feature.
+/// Every locale faker accepts it.
+///
+/// The document mirrors the [`Data`](super::Data) keys: lists like
+/// `NAME_FIRST_NAME` are arrays of strings, templates and formats like
+/// `NAME_TPL` are strings and `INTERNET_PASSWORD_CHARS` is an array of
+/// one-character strings. The optional `fallback` key names the built-in
+/// [`Locale`] providing the tables the document doesn't set, `en` by default.
+/// City names are built like the fallback does, e.g. with river names for
+/// `de_de`, unless the document sets one of the `ADDRESS_CITY_*` tables.
+///
+/// ```
+/// # #[cfg(feature = "toml")] {
+/// use fake::faker::address::raw::CityName;
+/// use fake::faker::name::raw::FirstName;
+/// use fake::locales::FileLocale;
+/// use fake::Fake;
+///
+/// let locale = FileLocale::from_toml(
+///     r#"
+///     fallback = "fr_fr"
+///     NAME_FIRST_NAME = ["Ada", "Grace"]
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let first: &str = FirstName(locale).fake();
+/// assert!(first == "Ada" || first == "Grace");
+/// let city: String = CityName(locale).fake();
+/// # }
+/// ```
+///
+/// The tables are leaked so that fakers can hand out `&'static str` like with
+/// the built-in locales: load each document once, e.g. at startup, and copy
+/// the `FileLocale` around.
+#[derive(Debug, Clone, Copy)]
+pub struct FileLocale(&'static Loaded);
+
+/// Error returned when loading a [`FileLocale`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileLocaleError {
+    /// The document is malformed at this line.
+    Syntax { line: usize, message: String },
+    /// No table with this name in [`Data`](super::Data).
+    UnknownTable(String),
+    /// The value of the table doesn't have the expected type.
+    InvalidTable {
+        table: String,
+        expected: &'static str,
+    },
+    /// The `fallback` key isn't a known locale.
+    Fallback(ParseLocaleError),
+}
+
+impl fmt::Display for FileLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileLocaleError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            FileLocaleError::UnknownTable(name) => write!(f, "unknown table `{}`", name),
+            FileLocaleError::InvalidTable { table, expected } => {
+                write!(f, "table `{}` must be {}", table, expected)
+            }
+            FileLocaleError::Fallback(err) => write!(f, "invalid fallback: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FileLocaleError {}
+
+/// Value of a key of the document.
+enum Value {
+    Str(String),
+    List(Vec<String>),
+    /// Anything else.
+    Other,
+}
+
+/// Value of a table, checked against its type but not leaked yet.
+enum Checked {
+    Str(String),
+    List(Vec<String>),
+    Chars(Vec<char>),
+}
+
+/// Conversion of a [`Value`] to the type of a table.
+trait FromValue: Sized {
+    const EXPECTED: &'static str;
+
+    /// Check that `value` has the type of the table.
+    fn check(value: Value) -> Option<Checked>;
+
+    /// Leak a value accepted by [`check`](FromValue::check).
+    fn leak(value: Checked) -> Self;
+}
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+impl FromValue for &'static str {
+    const EXPECTED: &'static str = "a string";
+
+    fn check(value: Value) -> Option<Checked> {
+        match value {
+            Value::Str(s) => Some(Checked::Str(s)),
+            _ => None,
+        }
+    }
+
+    fn leak(value: Checked) -> Self {
+        match value {
+            Checked::Str(s) => leak(s),
+            _ => unreachable!("checked as a string"),
+        }
+    }
+}
+
+impl FromValue for &'static [&'static str] {
+    const EXPECTED: &'static str = "a non-empty array of strings";
+
+    fn check(value: Value) -> Option<Checked> {
+        match value {
+            Value::List(items) if !items.is_empty() => Some(Checked::List(items)),
+            _ => None,
+        }
+    }
+
+    fn leak(value: Checked) -> Self {
+        match value {
+            Checked::List(items) => items.into_iter().map(leak).collect::<Vec<_>>().leak(),
+            _ => unreachable!("checked as an array of strings"),
+        }
+    }
+}
+
+impl FromValue for &'static [char] {
+    const EXPECTED: &'static str = "a non-empty array of one-character strings";
+
+    fn check(value: Value) -> Option<Checked> {
+        match value {
+            Value::List(items) if !items.is_empty() => items
+                .iter()
+                .map(|item| {
+                    let mut chars = item.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Some(c),
+                        _ => None,
+                    }
+                })
+                .collect::<Option<Vec<_>>>()
+                .map(Checked::Chars),
+            _ => None,
+        }
+    }
+
+    fn leak(value: Checked) -> Self {
+        match value {
+            Checked::Chars(chars) => chars.leak(),
+            _ => unreachable!("checked as an array of characters"),
+        }
+    }
+}
+
+macro_rules! set_table {
+    ($($name:ident: $ty:ty;)+) => {
+        /// Check `value` against the type of the table `key`, returning the
+        /// name of the table.
+        fn check_table(key: &str, value: Value) -> Result<(&'static str, Checked), FileLocaleError> {
+            match key {
+                $(stringify!($name) => {
+                    let value = <$ty as FromValue>::check(value).ok_or_else(|| {
+                        FileLocaleError::InvalidTable {
+                            table: key.to_string(),
+                            expected: <$ty as FromValue>::EXPECTED,
+                        }
+                    })?;
+                    Ok((stringify!($name), value))
+                })+
+                _ => Err(FileLocaleError::UnknownTable(key.to_string())),
+            }
+        }
+
+        /// Set the table `name` of `tables` to `value`, leaking it.
+        fn set_table(tables: &mut LocaleTables, name: &str, value: Checked) {
+            match name {
+                $(stringify!($name) => tables.$name = <$ty as FromValue>::leak(value),)+
+                _ => unreachable!("checked table name"),
+            }
+        }
+    };
+}
+
+with_tables!(set_table);
+
+#[derive(Debug)]
+struct Loaded {
+    fallback: Locale,
+    tables: LocaleTables,
+    overridden: Vec<&'static str>,
+}
+
+impl Tables for FileLocale {
+    fn tables(&self) -> &'static LocaleTables {
+        &self.0.tables
+    }
+}
+
+impl CityNameGenFn for FileLocale {
+    fn gen<R: Rng + ?Sized>(c: &CityName<Self>, rng: &mut R) -> String {
+        // keep the way the fallback builds city names, unless the document
+        // brings its own city tables
+        let overridden = c.0.overridden_tables();
+        if overridden.iter().any(|t| t.starts_with("ADDRESS_CITY_")) {
+            address::city_name(c, rng)
+        } else {
+            with_locale!(c.0.fallback(), |l| c.for_locale(l).fake_with_rng(rng))
+        }
+    }
+}
+
+impl FileLocale {
+    fn new(entries: Vec<(String, Value)>) -> Result<Self, FileLocaleError> {
+        let mut fallback = Locale::En;
+        let mut tables = Vec::new();
+        for (key, value) in entries {
+            if key != "fallback" {
+                tables.push((key, value));
+                continue;
+            }
+            fallback = match value {
+                Value::Str(code) => code.parse().map_err(FileLocaleError::Fallback)?,
+                _ => {
+                    return Err(FileLocaleError::InvalidTable {
+                        table: key,
+                        expected: "a locale code",
+                    })
+                }
+            };
+        }
+
+        // check the whole document before leaking anything
+        let tables = tables
+            .into_iter()
+            .map(|(key, value)| check_table(&key, value))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut loaded = Loaded {
+            fallback,
+            tables: *with_locale!(fallback, |l| l.tables()),
+            overridden: Vec::new(),
+        };
+        for (table, value) in tables {
+            set_table(&mut loaded.tables, table, value);
+            loaded.overridden.push(table);
+        }
+        Ok(FileLocale(Box::leak(Box::new(loaded))))
+    }
+
+    /// Load the tables from a TOML document of top-level keys.
+    ///
+    /// A successful load leaks the tables of the document for the rest of the
+    /// program, so each reload leaks again. A failed load leaks nothing.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    pub fn from_toml(src: &str) -> Result<Self, FileLocaleError> {
+        // parsing into a `Value`, unlike a map, rejects duplicate keys
+        let document: toml::Value =
+            src.parse()
+                .map_err(|err: toml::de::Error| FileLocaleError::Syntax {
+                    line: err.line_col().map_or(0, |(line, _)| line + 1),
+                    message: err.to_string(),
+                })?;
+        let table = match document {
+            toml::Value::Table(table) => table,
+            _ => unreachable!("a TOML document is a table"),
+        };
+        let entries = table
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    toml::Value::String(s) => Value::Str(s),
+                    toml::Value::Array(items) => items
+                        .into_iter()
+                        .map(|item| match item {
+                            toml::Value::String(s) => Some(s),
+                            _ => None,
+                        })
+                        .collect::<Option<_>>()
+                        .map_or(Value::Other, Value::List),
+                    _ => Value::Other,
+                };
+                (key, value)
+            })
+            .collect();
+        FileLocale::new(entries)
+    }
+
+    /// Load the tables from a JSON object.
+    ///
+    /// A successful load leaks the tables of the document for the rest of the
+    /// program, so each reload leaks again. A failed load leaks nothing.
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn from_json(src: &str) -> Result<Self, FileLocaleError> {
+        let object: serde_json::Map<String, serde_json::Value> = serde_json::from_str(src)
+            .map_err(|err| FileLocaleError::Syntax {
+                line: err.line(),
+                message: err.to_string(),
+            })?;
+        let entries = object
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(s) => Value::Str(s),
+                    serde_json::Value::Array(items) => items
+                        .into_iter()
+                        .map(|item| match item {
+                            serde_json::Value::String(s) => Some(s),
+                            _ => None,
+                        })
+                        .collect::<Option<_>>()
+                        .map_or(Value::Other, Value::List),
+                    _ => Value::Other,
+                };
+                (key, value)
+            })
+            .collect();
+        FileLocale::new(entries)
+    }
+
+    /// Locale providing the tables the document doesn't set.
+    pub fn fallback(self) -> Locale {
+        self.0.fallback
+    }
+
+    /// Names of the tables set by the document.
+    pub fn overridden_tables(self) -> &'static [&'static str] {
+        &self.0.overridden
+    }
+}
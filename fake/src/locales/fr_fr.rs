@@ -1058,7 +1058,6 @@ impl Data for FR_FR {
         "Aubert",
         "Aubin",
         "Aubry",
-        "Aubry",
         "Auger",
         "Auvray",
         "Avril",
@@ -1067,7 +1066,6 @@ impl Data for FR_FR {
         "Baptiste",
         "Barbe",
         "Barbier",
-        "Barbier",
         "Bardet",
         "Baron",
         "Barre",
@@ -1339,7 +1337,6 @@ impl Data for FR_FR {
         "Ferrer",
         "Ferrier",
         "Ferry",
-        "Ferry",
         "Fischer",
         "Flament",
         "Fleury",
@@ -1584,7 +1581,6 @@ impl Data for FR_FR {
         "Li",
         "Loiseau",
         "Lombard",
-        "Lombard",
         "Lopes",
         "Lopez",
         "Louis",
@@ -1615,7 +1611,6 @@ impl Data for FR_FR {
         "Marteau",
         "Martel",
         "Martin",
-        "Martin",
         "Martineau",
         "Martinet",
         "Martinez",
@@ -1691,7 +1686,6 @@ impl Data for FR_FR {
         "Paris",
         "Parisot",
         "Parmentier",
-        "Parmentier",
         "Pascal",
         "Pasquet",
         "Pasquier",
@@ -0,0 +1,179 @@
+use super::Locale;
+use crate::Fake;
+use rand::Rng;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Locale picked among weighted [`Locale`]s, e.g. for a multinational users
+/// table. Every locale faker accepts it.
+///
+/// One locale is picked per record: while a [`MixedRecord`] is alive, every
+/// faker using equal `Mixed` weights gets the same locale, so that a name and
+/// its email match. Derived [`Dummy`](crate::Dummy) impls start one, unless
+/// marked `#[dummy(mixed_per_field)]`. Outside of a record, each generated
+/// value picks its own locale.
+///
+/// ```
+/// use fake::faker::internet::raw::SafeEmail;
+/// use fake::faker::name::raw::Name;
+/// use fake::locales::{Locale, Mixed};
+/// use fake::{Dummy, Fake, Faker};
+///
+/// const USERS: Mixed = Mixed(&[(Locale::En, 60), (Locale::FrFr, 25), (Locale::JaJp, 15)]);
+///
+/// #[derive(Debug, Dummy)]
+/// struct User {
+///     #[dummy(faker = "Name(USERS)")]
+///     name: String,
+///     #[dummy(faker = "SafeEmail(USERS)")]
+///     email: String,
+/// }
+///
+/// let user: User = Faker.fake();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mixed<'a>(pub &'a [(Locale, u32)]);
+
+impl Mixed<'_> {
+    /// Pick a locale according to the weights, or the one already picked by
+    /// the current [`MixedRecord`].
+    ///
+    /// # Panics
+    ///
+    /// If the weights sum to zero.
+    pub fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> Locale {
+        #[cfg(feature = "std")]
+        {
+            let picked = PICKED.with(|picked| {
+                let picked = picked.borrow();
+                let picked = picked.as_ref()?;
+                picked.iter().find(|(w, _)| w == self.0).map(|&(_, l)| l)
+            });
+            if let Some(locale) = picked {
+                return locale;
+            }
+            let locale = self.pick_with_rng(rng);
+            PICKED.with(|picked| {
+                if let Some(picked) = picked.borrow_mut().as_mut() {
+                    picked.push((self.0.to_vec(), locale));
+                }
+            });
+            locale
+        }
+        #[cfg(not(feature = "std"))]
+        self.pick_with_rng(rng)
+    }
+
+    fn pick_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Locale {
+        let total: u64 = self.0.iter().map(|&(_, weight)| u64::from(weight)).sum();
+        assert!(total > 0, "Mixed needs a positive total weight");
+        let mut n = (0..total).fake_with_rng::<u64, _>(rng);
+        for &(locale, weight) in self.0 {
+            if n < u64::from(weight) {
+                return locale;
+            }
+            n -= u64::from(weight);
+        }
+        unreachable!()
+    }
+}
+
+/// Locales picked in a record, by `Mixed` weights.
+#[cfg(feature = "std")]
+type Picked = Vec<(Vec<(Locale, u32)>, Locale)>;
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// Locales picked in the current record, `None` outside of a record.
+    static PICKED: RefCell<Option<Picked>> = const { RefCell::new(None) };
+}
+
+/// Guard making every [`Mixed`] pick the same locale until it's dropped.
+///
+/// Derived [`Dummy`](crate::Dummy) impls start one, so that the struct is a
+/// record. Records nest: the fields of an inner struct belong to the record of
+/// the outer one, but each element of a collection is a record of its own.
+/// Without the `std` feature it does nothing.
+///
+/// ```
+/// use fake::faker::name::raw::{FirstName, LastName};
+/// use fake::locales::{Locale, Mixed, MixedRecord};
+/// use fake::Fake;
+///
+/// let mixed = Mixed(&[(Locale::En, 1), (Locale::JaJp, 1)]);
+/// let _record = MixedRecord::start();
+/// let first: String = FirstName(mixed).fake();
+/// let last: String = LastName(mixed).fake();
+/// ```
+#[derive(Debug)]
+#[must_use = "the record ends when the guard is dropped"]
+pub struct MixedRecord {
+    #[cfg(feature = "std")]
+    outermost: bool,
+}
+
+impl MixedRecord {
+    /// Start a record, or join the current one.
+    pub fn start() -> Self {
+        #[cfg(feature = "std")]
+        {
+            let outermost = PICKED.with(|picked| {
+                let mut picked = picked.borrow_mut();
+                let outermost = picked.is_none();
+                if outermost {
+                    *picked = Some(Vec::new());
+                }
+                outermost
+            });
+            MixedRecord { outermost }
+        }
+        #[cfg(not(feature = "std"))]
+        MixedRecord {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for MixedRecord {
+    fn drop(&mut self) {
+        if self.outermost {
+            PICKED.with(|picked| *picked.borrow_mut() = None);
+        }
+    }
+}
+
+/// Guard making a collection element a record of its own while in a record,
+/// instead of joining it, see [`MixedRecord`].
+pub(crate) struct ElementRecord {
+    #[cfg(feature = "std")]
+    outer: Option<Picked>,
+}
+
+impl ElementRecord {
+    pub(crate) fn start() -> Self {
+        #[cfg(feature = "std")]
+        {
+            let outer = PICKED.with(|picked| {
+                let mut picked = picked.borrow_mut();
+                let outer = picked.take();
+                if outer.is_some() {
+                    *picked = Some(Vec::new());
+                }
+                outer
+            });
+            ElementRecord { outer }
+        }
+        #[cfg(not(feature = "std"))]
+        ElementRecord {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for ElementRecord {
+    fn drop(&mut self) {
+        if let Some(outer) = self.outer.take() {
+            PICKED.with(|picked| *picked.borrow_mut() = Some(outer));
+        }
+    }
+}
@@ -528,7 +528,6 @@ impl Data for PT_BR {
         "Fernandes",
         "Ferraz",
         "Ferreira",
-        "Ferreira",
         "Fidalgo",
         "Flores",
         "Fonseca",
@@ -587,8 +586,6 @@ impl Data for PT_BR {
         "Pontes",
         "Prado",
         "Queirós",
-        "Queirós",
-        "Quintana",
         "Quintana",
         "Ramires",
         "Ramos",
@@ -1,10 +1,11 @@
-use rand::seq::IndexedRandom;
+use crate::stable::IndexedRandom;
 
 use crate::{
     faker::{automotive::raw::LicencePlate, impls::address::CityNameGenFn},
     locales::Data,
     Dummy,
 };
+use alloc::string::String;
 
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
@@ -201,7 +202,6 @@ impl Data for PT_PT {
         "Nogueira",
         "Pais",
         "Queirós",
-        "Ramos",
         "Raposo",
         "Salgueiro",
         "Sampaio",
@@ -536,7 +536,7 @@ impl Data for PT_PT {
     const ADDRESS_STREET_SUFFIX: &'static [&'static str] = &[
         "Alameda", "Avenida", "Calçada", "Estrada", "Largo", "Praça", "Rua", "Travessa",
     ];
-    const ADDRESS_STREET_TPL: &'static str = "{StreetSuffix} {FirstName} {LastName}";
+    const ADDRESS_STREET_TPL: &'static str = "{StreetSuffix} {StreetName}";
     const ADDRESS_TIME_ZONE: &'static [&'static str] =
         &["Atlantic/Azores", "Atlantic/Madeira", "Europe/Lisbon"];
     const ADDRESS_ZIP_FORMATS: &'static [&'static str] = &["####-###"];
@@ -560,8 +560,7 @@ impl Data for PT_PT {
         "EUR", "USD", "GBP", "JPY", "CHF", "AUD", "CAD", "CNY", "BRL", "MXN",
     ];
 
-    const CURRENCY_SYMBOL: &'static [&'static str] =
-        &["€", "$", "£", "¥", "Fr.", "A$", "C$", "¥", "R$", "$"];
+    const CURRENCY_SYMBOL: &'static [&'static str] = &["€", "$", "£", "¥", "Fr.", "A$", "C$", "R$"];
 
     // Add company related constants
     const COMPANY_SUFFIX: &'static [&'static str] = &[
@@ -0,0 +1,146 @@
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+
+macro_rules! locales {
+    ($d:tt $($(#[$doc:meta])* $variant:ident($static:ident) => $code:literal,)+) => {
+        /// Locale selected at runtime, e.g. from a config file or a request.
+        ///
+        /// Every locale faker accepts it in place of a static locale like
+        /// [`EN`](super::EN), dispatching to that locale's [`Data`](super::Data).
+        ///
+        /// ```
+        /// use fake::faker::name::raw::Name;
+        /// use fake::locales::Locale;
+        /// use fake::Fake;
+        ///
+        /// let locale: Locale = "fr_fr".parse().unwrap();
+        /// let name: String = Name(locale).fake();
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Locale {
+            $($(#[$doc])* $variant,)+
+        }
+
+        impl Locale {
+            /// All the locales, in declaration order.
+            pub const ALL: &'static [Locale] = &[$(Locale::$variant,)+];
+
+            /// Locale providing the tables this one doesn't override, `None`
+            /// for [`Locale::En`] which provides the defaults.
+            pub fn parent(self) -> Option<Locale> {
+                match self {
+                    $(Locale::$variant => <super::$static as super::Data>::PARENT,)+
+                }
+            }
+
+            /// Lowercase code of the locale, e.g. `fr_fr`.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(Locale::$variant => $code,)+
+                }
+            }
+        }
+
+        $(
+            impl StaticLocale for super::$static {
+                const LOCALE: Locale = Locale::$variant;
+            }
+        )+
+
+        /// Evaluate `$body` with `$l` bound to the static locale of `$locale`.
+        macro_rules! with_locale {
+            ($d locale:expr, |$d l:ident| $d body:expr) => {
+                match $d locale {
+                    $($crate::locales::Locale::$variant => {
+                        let $d l = $crate::locales::$static;
+                        $d body
+                    })+
+                }
+            };
+        }
+    };
+}
+
+/// [`Locale`] variant of a static locale.
+pub(crate) trait StaticLocale {
+    const LOCALE: Locale;
+}
+
+locales! {$
+    /// [`EN`](super::EN)
+    En(EN) => "en",
+    /// [`FR_FR`](super::FR_FR)
+    FrFr(FR_FR) => "fr_fr",
+    /// [`ZH_TW`](super::ZH_TW)
+    ZhTw(ZH_TW) => "zh_tw",
+    /// [`ZH_CN`](super::ZH_CN)
+    ZhCn(ZH_CN) => "zh_cn",
+    /// [`JA_JP`](super::JA_JP)
+    JaJp(JA_JP) => "ja_jp",
+    /// [`AR_SA`](super::AR_SA)
+    ArSa(AR_SA) => "ar_sa",
+    /// [`PT_BR`](super::PT_BR)
+    PtBr(PT_BR) => "pt_br",
+    /// [`PT_PT`](super::PT_PT)
+    PtPt(PT_PT) => "pt_pt",
+    /// [`DE_DE`](super::DE_DE)
+    DeDe(DE_DE) => "de_de",
+    /// [`DE_AT`](super::DE_AT)
+    DeAt(DE_AT) => "de_at",
+    /// [`DE_CH`](super::DE_CH)
+    DeCh(DE_CH) => "de_ch",
+    /// [`DE_LI`](super::DE_LI)
+    DeLi(DE_LI) => "de_li",
+    /// [`IT_IT`](super::IT_IT)
+    ItIt(IT_IT) => "it_it",
+}
+
+pub(crate) use with_locale;
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Parses a locale code case-insensitively, with `_` or `-` as separator,
+/// e.g. `fr_fr` or `fr-FR`.
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: String = s
+            .chars()
+            .map(|c| match c {
+                '-' => '_',
+                c => c.to_ascii_lowercase(),
+            })
+            .collect();
+        Locale::ALL
+            .iter()
+            .find(|locale| locale.code() == code)
+            .copied()
+            .ok_or_else(|| ParseLocaleError(s.into()))
+    }
+}
+
+/// Error returned when parsing an unknown [`Locale`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLocaleError(pub String);
+
+impl fmt::Display for ParseLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown locale `{}`, expected one of ", self.0)?;
+        for (i, locale) in Locale::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(locale.code())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLocaleError {}
@@ -0,0 +1,298 @@
+use super::{LocaleTables, Tables};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Placeholders replaced by the faker rendering each template table.
+const TEMPLATES: &[(&str, &[&str])] = &[
+    ("NAME_TPL", &["FirstName", "LastName"]),
+    ("NAME_WITH_TITLE_TPL", &["Title", "FirstName", "LastName"]),
+    ("JOB_TITLE_TPL", &["Seniority", "Field", "Position"]),
+    ("ADDRESS_CITY_TPL", &["CityName", "CitySuffix"]),
+    (
+        "ADDRESS_CITY_WITH_PREFIX_TPL",
+        &["CityPrefix", "CityName", "CitySuffix"],
+    ),
+    ("ADDRESS_STREET_TPL", &["StreetName", "StreetSuffix"]),
+    (
+        "ADDRESS_SECONDARY_ADDR_TPL",
+        &["SecondaryAddrType", "Number"],
+    ),
+    ("COMPANY_NAME_TPLS", &["Name_1", "Name_2", "Suffix"]),
+    ("COMPANY_CATCH_PHASE_TPL", &["Head", "Middle", "Tail"]),
+    ("COMPANY_BS_TPL", &["Verb", "Adj", "Noun"]),
+];
+
+/// Digits of an ISBN-13. A [`RegistrantRule`](super::RegistrantRule) matches
+/// the ones left once the EAN prefix, the group, the last publication digit
+/// and the check digit are removed.
+const ISBN_LENGTH: usize = 13;
+
+/// Problem found by [`validate`] in the tables of a locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// The table has no entries.
+    Empty(&'static str),
+    /// The table contains this entry more than once.
+    Duplicate { table: &'static str, entry: String },
+    /// The template uses a placeholder its faker doesn't replace.
+    UnknownPlaceholder {
+        table: &'static str,
+        placeholder: String,
+    },
+    /// The format can't be rendered.
+    MalformedFormat {
+        table: &'static str,
+        format: &'static str,
+    },
+    /// The ISBN rule at this index of the group is invalid.
+    IsbnRule {
+        group: &'static str,
+        index: usize,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::Empty(table) => write!(f, "table `{}` is empty", table),
+            Issue::Duplicate { table, entry } => {
+                write!(f, "table `{}` contains `{}` more than once", table, entry)
+            }
+            Issue::UnknownPlaceholder { table, placeholder } => {
+                write!(
+                    f,
+                    "table `{}` uses unknown placeholder `{{{}}}`",
+                    table, placeholder
+                )
+            }
+            Issue::MalformedFormat { table, format } => {
+                write!(
+                    f,
+                    "table `{}` contains malformed format `{}`",
+                    table, format
+                )
+            }
+            Issue::IsbnRule {
+                group,
+                index,
+                reason,
+            } => write!(f, "ISBN rule {} of group `{}`: {}", index, group, reason),
+        }
+    }
+}
+
+/// A table of [`LocaleTables`], checked for emptiness and duplicates.
+trait Table: Copy {
+    fn entries(self) -> Vec<String>;
+}
+
+impl Table for &'static [&'static str] {
+    fn entries(self) -> Vec<String> {
+        self.iter().map(|s| s.to_string()).collect()
+    }
+}
+
+impl Table for &'static [char] {
+    fn entries(self) -> Vec<String> {
+        self.iter().map(|c| c.to_string()).collect()
+    }
+}
+
+impl Table for &'static str {
+    fn entries(self) -> Vec<String> {
+        if self.is_empty() {
+            Vec::new()
+        } else {
+            alloc::vec![self.to_string()]
+        }
+    }
+}
+
+/// Every table of `tables` with its name, as strings.
+macro_rules! all_tables {
+    ($($name:ident: $ty:ty;)+) => {
+        fn all_tables(tables: &LocaleTables) -> Vec<(&'static str, Vec<String>)> {
+            alloc::vec![$((stringify!($name), Table::entries(tables.$name)),)+]
+        }
+    };
+}
+
+with_tables!(all_tables);
+
+/// Check the tables of `locale`:
+///
+/// - every table is non-empty and without duplicate entries,
+/// - templates only use the placeholders their faker replaces,
+/// - number formats contain a digit to render and no placeholder,
+/// - ISBN registrant rules have the expected length, `min <= max`, and are
+///   sorted without overlapping.
+///
+/// It works for built-in locales as well as for a
+/// [`FileLocale`](super::FileLocale) or a custom [`Data`](super::Data).
+///
+/// ```
+/// use fake::locales::{validate, Issue, EN};
+///
+/// assert_eq!(validate(&EN), Ok(()));
+/// ```
+pub fn validate<L: Tables>(locale: &L) -> Result<(), Vec<Issue>> {
+    let tables = locale.tables();
+    let mut issues = Vec::new();
+
+    for (table, entries) in all_tables(tables) {
+        if entries.is_empty() {
+            issues.push(Issue::Empty(table));
+        }
+        for (i, entry) in entries.iter().enumerate() {
+            if entries[..i].contains(entry) && !entries[i + 1..].contains(entry) {
+                issues.push(Issue::Duplicate {
+                    table,
+                    entry: entry.clone(),
+                });
+            }
+        }
+
+        if let Some((_, known)) = TEMPLATES.iter().find(|(name, _)| *name == table) {
+            for template in &entries {
+                for placeholder in placeholders(template) {
+                    if !known.contains(&placeholder) {
+                        issues.push(Issue::UnknownPlaceholder {
+                            table,
+                            placeholder: placeholder.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for (table, formats) in [
+        (
+            "ADDRESS_BUILDING_NUMBER_FORMATS",
+            tables.ADDRESS_BUILDING_NUMBER_FORMATS,
+        ),
+        ("ADDRESS_ZIP_FORMATS", tables.ADDRESS_ZIP_FORMATS),
+        ("ADDRESS_POSTCODE_FORMATS", tables.ADDRESS_POSTCODE_FORMATS),
+        ("PHONE_NUMBER_FORMATS", tables.PHONE_NUMBER_FORMATS),
+        (
+            "PHONE_CELL_NUMBER_FORMATS",
+            tables.PHONE_CELL_NUMBER_FORMATS,
+        ),
+    ] {
+        // `#` and `^` are replaced by digits, anything else is kept as is
+        for &format in formats {
+            if !format.contains(['#', '^'])
+                || format.contains(['{', '}'])
+                || format.trim() != format
+            {
+                issues.push(Issue::MalformedFormat { table, format });
+            }
+        }
+    }
+
+    // chrono and time formats, checked when they are used
+    #[cfg(feature = "chrono")]
+    for (table, format) in [
+        (
+            "CHRONO_DEFAULT_TIME_FORMAT",
+            tables.CHRONO_DEFAULT_TIME_FORMAT,
+        ),
+        (
+            "CHRONO_DEFAULT_DATE_FORMAT",
+            tables.CHRONO_DEFAULT_DATE_FORMAT,
+        ),
+        (
+            "CHRONO_DEFAULT_DATETIME_FORMAT",
+            tables.CHRONO_DEFAULT_DATETIME_FORMAT,
+        ),
+    ] {
+        let mut items = chrono::format::StrftimeItems::new(format);
+        if items.any(|item| item == chrono::format::Item::Error) {
+            issues.push(Issue::MalformedFormat { table, format });
+        }
+    }
+
+    #[cfg(feature = "time")]
+    for (table, format) in [
+        ("TIME_DEFAULT_TIME_FORMAT", tables.TIME_DEFAULT_TIME_FORMAT),
+        ("TIME_DEFAULT_DATE_FORMAT", tables.TIME_DEFAULT_DATE_FORMAT),
+        (
+            "TIME_DEFAULT_DATETIME_FORMAT",
+            tables.TIME_DEFAULT_DATETIME_FORMAT,
+        ),
+    ] {
+        if time::format_description::parse_borrowed::<1>(format).is_err() {
+            issues.push(Issue::MalformedFormat { table, format });
+        }
+    }
+
+    check_isbn_rules(tables, &mut issues);
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+/// Names between braces in `template`, or what follows an unclosed `{`.
+fn placeholders(template: &str) -> Vec<&str> {
+    let mut placeholders = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        match rest.find('}') {
+            Some(end) => {
+                placeholders.push(&rest[..end]);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                placeholders.push(rest);
+                break;
+            }
+        }
+    }
+    placeholders
+}
+
+fn check_isbn_rules(tables: &LocaleTables, issues: &mut Vec<Issue>) {
+    let ean = tables.ISBN_EAN;
+    if ean.len() != 3 || !ean.bytes().all(|b| b.is_ascii_digit()) {
+        issues.push(Issue::MalformedFormat {
+            table: "ISBN_EAN",
+            format: ean,
+        });
+    }
+
+    for (&group, rules) in (tables.isbn_rules)().iter() {
+        let len = ISBN_LENGTH
+            .saturating_sub(ean.len() + group.len() + 2)
+            .max(1);
+        let mut invalid = |index, reason| {
+            issues.push(Issue::IsbnRule {
+                group,
+                index,
+                reason,
+            })
+        };
+
+        if rules.is_empty() {
+            invalid(0, "the group has no rule");
+        }
+        for (index, rule) in rules.iter().enumerate() {
+            let digits = |s: &str| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+            if !digits(rule.min) || !digits(rule.max) {
+                invalid(index, "`min` and `max` must have the rule length in digits");
+            } else if rule.min > rule.max {
+                invalid(index, "`min` is greater than `max`");
+            } else if index > 0 && rules[index - 1].max >= rule.min {
+                invalid(index, "`min` is not greater than the previous `max`");
+            }
+            if rule.registrant_len < 1 || rule.registrant_len as usize > len {
+                invalid(index, "`registrant_len` is out of range");
+            }
+        }
+    }
+}
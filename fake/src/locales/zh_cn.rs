@@ -83,7 +83,6 @@ impl Data for ZH_CN {
         "经理",
         "工程师",
         "专员",
-        "主任",
         "协调员",
         "行政",
         "建筑师",
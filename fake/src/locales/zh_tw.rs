@@ -28,25 +28,25 @@ impl Data for ZH_TW {
         "熊", "紀", "舒", "屈", "項", "祝", "董", "梁", "杜", "阮", "藍", "閔", "席", "季", "麻",
         "強", "賈", "路", "婁", "危", "江", "童", "顏", "郭", "梅", "盛", "林", "刁", "鍾", "徐",
         "邱", "駱", "高", "夏", "蔡", "田", "樊", "胡", "淩", "霍", "虞", "萬", "支", "柯", "昝",
-        "管", "盧", "莫", "柯", "房", "裘", "繆", "幹", "解", "應", "宗", "丁", "宣", "賁", "鄧",
-        "鬱", "單", "杭", "洪", "包", "諸", "左", "石", "崔", "吉", "鈕", "龔", "程", "嵇", "邢",
-        "滑", "裴", "陸", "榮", "翁", "荀", "羊", "于", "惠", "甄", "曲", "家", "封", "芮", "羿",
-        "儲", "靳", "汲", "邴", "糜", "松", "井", "段", "富", "巫", "烏", "焦", "巴", "弓", "牧",
-        "隗", "山", "穀", "車", "侯", "宓", "蓬", "全", "郗", "班", "仰", "秋", "仲", "伊", "宮",
-        "甯", "仇", "欒", "暴", "甘", "鈄", "曆", "戎", "祖", "武", "符", "劉", "景", "詹", "束",
-        "龍", "葉", "幸", "司", "韶", "郜", "黎", "薊", "溥", "印", "宿", "白", "懷", "蒲", "邰",
-        "從", "鄂", "索", "鹹", "籍", "賴", "卓", "藺", "屠", "蒙", "池", "喬", "陽", "鬱", "胥",
-        "能", "蒼", "雙", "聞", "莘", "党", "翟", "譚", "貢", "勞", "逄", "姬", "申", "扶", "堵",
-        "冉", "宰", "酈", "雍", "卻", "璩", "桑", "桂", "濮", "牛", "壽", "通", "邊", "扈", "燕",
-        "冀", "浦", "尚", "農", "溫", "別", "莊", "晏", "柴", "瞿", "閻", "充", "慕", "連", "茹",
-        "習", "宦", "艾", "魚", "容", "向", "古", "易", "慎", "戈", "廖", "庾", "終", "暨", "居",
-        "衡", "步", "都", "耿", "滿", "弘", "匡", "國", "文", "寇", "廣", "祿", "闕", "東", "歐",
-        "殳", "沃", "利", "蔚", "越", "夔", "隆", "師", "鞏", "厙", "聶", "晁", "勾", "敖", "融",
-        "冷", "訾", "辛", "闞", "那", "簡", "饒", "空", "曾", "毋", "沙", "乜", "養", "鞠", "須",
-        "豐", "巢", "關", "蒯", "相", "查", "後", "荊", "紅", "遊", "竺", "權", "逮", "盍", "益",
-        "桓", "公", "萬俟", "司馬", "上官", "歐陽", "夏侯", "諸葛", "聞人", "東方", "赫連", "皇甫",
-        "尉遲", "公羊", "澹台", "公冶", "宗政", "濮陽", "淳于", "單於", "太叔", "申屠", "公孫",
-        "仲孫", "軒轅", "令狐", "徐離", "宇文", "長孫", "慕容", "司徒", "司空",
+        "管", "盧", "莫", "房", "裘", "繆", "幹", "解", "應", "宗", "丁", "宣", "賁", "鄧", "鬱",
+        "單", "杭", "洪", "包", "諸", "左", "石", "崔", "吉", "鈕", "龔", "程", "嵇", "邢", "滑",
+        "裴", "陸", "榮", "翁", "荀", "羊", "于", "惠", "甄", "曲", "家", "封", "芮", "羿", "儲",
+        "靳", "汲", "邴", "糜", "松", "井", "段", "富", "巫", "烏", "焦", "巴", "弓", "牧", "隗",
+        "山", "穀", "車", "侯", "宓", "蓬", "全", "郗", "班", "仰", "秋", "仲", "伊", "宮", "甯",
+        "仇", "欒", "暴", "甘", "鈄", "曆", "戎", "祖", "武", "符", "劉", "景", "詹", "束", "龍",
+        "葉", "幸", "司", "韶", "郜", "黎", "薊", "溥", "印", "宿", "白", "懷", "蒲", "邰", "從",
+        "鄂", "索", "鹹", "籍", "賴", "卓", "藺", "屠", "蒙", "池", "喬", "陽", "胥", "能", "蒼",
+        "雙", "聞", "莘", "党", "翟", "譚", "貢", "勞", "逄", "姬", "申", "扶", "堵", "冉", "宰",
+        "酈", "雍", "卻", "璩", "桑", "桂", "濮", "牛", "壽", "通", "邊", "扈", "燕", "冀", "浦",
+        "尚", "農", "溫", "別", "莊", "晏", "柴", "瞿", "閻", "充", "慕", "連", "茹", "習", "宦",
+        "艾", "魚", "容", "向", "古", "易", "慎", "戈", "廖", "庾", "終", "暨", "居", "衡", "步",
+        "都", "耿", "滿", "弘", "匡", "國", "文", "寇", "廣", "祿", "闕", "東", "歐", "殳", "沃",
+        "利", "蔚", "越", "夔", "隆", "師", "鞏", "厙", "聶", "晁", "勾", "敖", "融", "冷", "訾",
+        "辛", "闞", "那", "簡", "饒", "空", "曾", "毋", "沙", "乜", "養", "鞠", "須", "豐", "巢",
+        "關", "蒯", "相", "查", "後", "荊", "紅", "遊", "竺", "權", "逮", "盍", "益", "桓", "公",
+        "萬俟", "司馬", "上官", "歐陽", "夏侯", "諸葛", "聞人", "東方", "赫連", "皇甫", "尉遲",
+        "公羊", "澹台", "公冶", "宗政", "濮陽", "淳于", "單於", "太叔", "申屠", "公孫", "仲孫",
+        "軒轅", "令狐", "徐離", "宇文", "長孫", "慕容", "司徒", "司空",
     ];
     const NAME_TITLE: &'static [&'static str] = &["先生", "夫人", "女士", "小姐", "博士"];
     const NAME_TPL: &'static str = "{LastName}{FirstName}";
@@ -0,0 +1,165 @@
+//! Pools of pre-generated values, for realistic cardinality and skew.
+//!
+//! A [`Pool`] runs a faker `n` times up front and then samples from the
+//! generated values, so a column gets at most `n` distinct values, e.g. 50
+//! companies shared by 10 000 employees. Values are sampled uniformly, by
+//! [`weights`](Pool::weights), or following a [Zipf](Pool::zipf) law where a
+//! few values are very frequent. Expensive fakers like `CompanyName` or
+//! `Paragraph` also only run `n` times.
+//!
+//! See [`WrappedVal`] for the types a pool generates directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::faker::company::en::CompanyName;
+//! use fake::faker::name::en::Name;
+//! use fake::pool::Pool;
+//! use fake::Fake;
+//! use std::collections::HashSet;
+//!
+//! // 50 companies, the first ones employing most people
+//! let companies = Pool::new(CompanyName(), 50).zipf(1.1);
+//! let employees: Vec<(String, String)> = (0..1000)
+//!     .map(|_| (Name().fake(), companies.fake()))
+//!     .collect();
+//!
+//! let distinct: HashSet<&String> = employees.iter().map(|(_, c)| c).collect();
+//! assert!(distinct.len() <= 50);
+//! ```
+
+use crate::utils::WrappedVal;
+use crate::{Dummy, Fake};
+use alloc::{string::String, vec::Vec};
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use rand::Rng;
+use rand_distr as distr;
+
+/// Faker sampling from values generated once by another faker.
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::lorem::en::Paragraph;
+/// use fake::pool::Pool;
+/// use fake::Fake;
+///
+/// let pool = Pool::new(Paragraph(3..5), 10);
+/// let text: String = pool.fake();
+/// assert!(pool.values().contains(&text));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pool<T> {
+    values: Vec<T>,
+    sampling: Sampling,
+}
+
+#[derive(Debug, Clone)]
+enum Sampling {
+    Uniform,
+    Weighted(WeightedIndex<f64>),
+    Zipf(distr::Zipf<f64>),
+}
+
+impl<T> Pool<T> {
+    /// Generate `n` values with `faker`.
+    ///
+    /// Panics if `n` is `0`.
+    #[cfg(feature = "std")]
+    pub fn new<C>(faker: C, n: usize) -> Self
+    where
+        T: Dummy<C>,
+    {
+        Self::new_with_rng(faker, n, &mut crate::seed::FakeRng::new())
+    }
+
+    /// Generate `n` values with `faker`, drawing from `rng`.
+    ///
+    /// Panics if `n` is `0`.
+    pub fn new_with_rng<C, R: Rng + ?Sized>(faker: C, n: usize, rng: &mut R) -> Self
+    where
+        T: Dummy<C>,
+    {
+        Self::from_values((0..n).map(|_| faker.fake_with_rng(rng)).collect())
+    }
+
+    /// Sample from the given values instead of generated ones.
+    ///
+    /// Panics if `values` is empty.
+    pub fn from_values(values: Vec<T>) -> Self {
+        assert!(!values.is_empty(), "Pool needs at least one value");
+        Pool {
+            values,
+            sampling: Sampling::Uniform,
+        }
+    }
+
+    /// Sample values proportionally to `weights`, one weight per value.
+    pub fn weights<I>(mut self, weights: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<f64>,
+    {
+        let weights: Vec<f64> = weights.into_iter().map(Into::into).collect();
+        assert_eq!(
+            weights.len(),
+            self.values.len(),
+            "expected one weight per value"
+        );
+        let dist = WeightedIndex::new(&weights).expect("Can sample weighted");
+        self.sampling = Sampling::Weighted(dist);
+        self
+    }
+
+    /// Sample values following a Zipf law with exponent `s`: the value at
+    /// index `k` has a weight of `1 / (k + 1)^s`. `0.0` is uniform, higher
+    /// values make the first ones more frequent.
+    ///
+    /// Panics if `s` is negative or NaN.
+    pub fn zipf(mut self, s: f64) -> Self {
+        let dist = distr::Zipf::new(self.values.len() as f64, s).expect("valid zipf distribution");
+        self.sampling = Sampling::Zipf(dist);
+        self
+    }
+
+    /// The generated values.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        let index = match &self.sampling {
+            Sampling::Uniform => (0..self.values.len()).fake_with_rng(rng),
+            Sampling::Weighted(dist) => dist.sample(rng),
+            // ranks start at 1
+            Sampling::Zipf(dist) => dist.sample(rng) as usize - 1,
+        };
+        &self.values[index]
+    }
+}
+
+macro_rules! pool_impl {
+    ([$($gen:tt)*] $typ:ty => $inner:ty, $wrap:expr) => {
+        impl<$($gen)*> Dummy<Pool<$inner>> for $typ
+        where
+            $inner: Clone,
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &Pool<$inner>, rng: &mut R) -> Self {
+                $wrap(config.sample(rng).clone())
+            }
+        }
+    };
+    ($($typ:ty),*) => {
+        $(
+            pool_impl!([] $typ => $typ, core::convert::identity);
+        )*
+    };
+}
+
+pool_impl!(
+    String, bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+pool_impl!(['a,] &'a str => &'a str, core::convert::identity);
+pool_impl!([X,] Vec<X> => Vec<X>, core::convert::identity);
+pool_impl!([X,] WrappedVal<X> => X, WrappedVal::new);
@@ -0,0 +1,115 @@
+//! [`proptest`](https://docs.rs/proptest) strategies out of fakers.
+//!
+//! Every value is generated from an RNG seeded by proptest's
+//! [`TestRunner`], so failing cases are reproduced and persisted by
+//! proptest as usual. Fake values are not shrunk.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::faker::internet::en::SafeEmail;
+//! use fake::faker::name::en::Name;
+//! use fake::Dummy;
+//! use proptest::prelude::*;
+//!
+//! #[derive(Debug, Clone, Dummy)]
+//! struct User {
+//!     #[dummy(faker = "Name()")]
+//!     name: String,
+//!     #[dummy(faker = "SafeEmail()")]
+//!     email: String,
+//! }
+//!
+//! let mut runner = proptest::test_runner::TestRunner::default();
+//! runner
+//!     .run(&fake::proptest::strategy::<String, _>(Name()), |name| {
+//!         prop_assert!(!name.is_empty());
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! runner
+//!     .run(&fake::proptest::any_fake::<User>(), |user| {
+//!         prop_assert!(user.email.contains('@'));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+//!
+//! [`TestRunner`]: ::proptest::test_runner::TestRunner
+
+use crate::{Dummy, Faker};
+use ::proptest::arbitrary::any;
+use ::proptest::strategy::{NewTree, Strategy, ValueTree};
+use ::proptest::test_runner::TestRunner;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Strategy generating `T` out of config `C`, see [`strategy`].
+pub struct FakeStrategy<T, C> {
+    config: C,
+    _output: PhantomData<fn() -> T>,
+}
+
+/// Strategy generating `T` with the given config.
+pub fn strategy<T, C>(config: C) -> FakeStrategy<T, C>
+where
+    T: Dummy<C> + Clone + fmt::Debug,
+{
+    FakeStrategy {
+        config,
+        _output: PhantomData,
+    }
+}
+
+/// Strategy generating `T` with [`Faker`], e.g. for derived types.
+pub fn any_fake<T>() -> FakeStrategy<T, Faker>
+where
+    T: Dummy<Faker> + Clone + fmt::Debug,
+{
+    strategy(Faker)
+}
+
+impl<T, C> fmt::Debug for FakeStrategy<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakeStrategy")
+            .field("output", &std::any::type_name::<T>())
+            .field("config", &std::any::type_name::<C>())
+            .finish()
+    }
+}
+
+impl<T, C> Strategy for FakeStrategy<T, C>
+where
+    T: Dummy<C> + Clone + fmt::Debug,
+{
+    type Tree = FakeValueTree<T>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let seed = any::<[u8; 32]>().new_tree(runner)?.current();
+        let mut rng = StdRng::from_seed(seed);
+        Ok(FakeValueTree(T::dummy_with_rng(&self.config, &mut rng)))
+    }
+}
+
+/// Value tree of a [`FakeStrategy`], which never shrinks.
+#[derive(Debug, Clone)]
+pub struct FakeValueTree<T>(T);
+
+impl<T: Clone + fmt::Debug> ValueTree for FakeValueTree<T> {
+    type Value = T;
+
+    fn current(&self) -> T {
+        self.0.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        false
+    }
+}
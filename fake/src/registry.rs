@@ -0,0 +1,389 @@
+//! Runtime registry of the locale fakers, looked up by name.
+//!
+//! Every faker of the [`faker`](crate::faker) module which works in any
+//! locale is described by a [`FakerInfo`]: its module (the category), its name,
+//! its parameter if any and the type of its output. A [`DynFaker`] built out of
+//! it for a locale generates a [`String`] or a typed [`Value`], so generators
+//! can be picked from a config file or the command line.
+//!
+//! Fakers are named `Name`, or `category.Name` to disambiguate, e.g.
+//! `job.Title` as `Title` is `name.Title`.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::locales::Locale;
+//! use fake::registry::{self, Value};
+//! use fake::Fake;
+//!
+//! let city = registry::build("CityName", Locale::FrFr, None).unwrap();
+//! let city: String = city.fake();
+//!
+//! let words = registry::build("lorem.Words", Locale::En, Some("2..4")).unwrap();
+//! if let Value::List(words) = words.fake() {
+//!     assert!((2..4).contains(&words.len()));
+//! }
+//!
+//! for info in registry::fakers() {
+//!     println!("{}.{} {:?}", info.category(), info.name(), info.param());
+//! }
+//! ```
+
+use crate::faker::impls::address::CityNameGenFn;
+use crate::locales::{with_locale, Locale};
+use crate::{Dummy, Fake};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use core::ops::Range;
+use rand::{Rng, RngCore};
+
+/// Kind of the parameter of a faker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    /// A `usize` range written `3..5` or `3..=4`, or a single number.
+    Range,
+    /// A `u8` number.
+    U8,
+    /// Any string.
+    Str,
+}
+
+/// Parameter of a faker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub kind: ParamKind,
+    /// Used when no argument is given.
+    pub default: &'static str,
+}
+
+/// Type of the values generated by a faker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    String,
+    Bool,
+    Float,
+    List,
+}
+
+/// Value generated by a [`DynFaker`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Bool(bool),
+    Float(f64),
+    List(Vec<String>),
+}
+
+/// Description of a faker in the registry.
+#[derive(Debug)]
+pub struct FakerInfo {
+    category: &'static str,
+    name: &'static str,
+    param: Option<Param>,
+    output: Output,
+    separator: &'static str,
+}
+
+pub(crate) enum Arg {
+    None,
+    Range(Range<usize>),
+    U8(u8),
+    Str(String),
+}
+
+impl Arg {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Arg::Range(r) => r.clone(),
+            _ => unreachable!("argument checked while parsing"),
+        }
+    }
+
+    fn u8(&self) -> u8 {
+        match self {
+            Arg::U8(n) => *n,
+            _ => unreachable!("argument checked while parsing"),
+        }
+    }
+
+    fn str(&self) -> &str {
+        match self {
+            Arg::Str(s) => s,
+            _ => unreachable!("argument checked while parsing"),
+        }
+    }
+}
+
+pub(crate) type Gen<L> = fn(L, &Arg, &mut dyn RngCore) -> Value;
+
+type BoxedGen = Box<dyn Fn(&mut dyn RngCore) -> Value + Send + Sync>;
+
+/// Error returned when building a [`DynFaker`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// No faker with this name.
+    UnknownFaker(String),
+    /// The argument is unexpected or malformed for this faker.
+    InvalidArgument { faker: String, arg: String },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownFaker(name) => write!(f, "unknown faker `{}`", name),
+            RegistryError::InvalidArgument { faker, arg } => {
+                write!(f, "invalid argument `{}` for faker `{}`", arg, faker)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegistryError {}
+
+macro_rules! registry {
+    ($(
+        $(#[$meta:meta])*
+        $cat:ident: $(
+            $(#[$faker_meta:meta])*
+            $name:ident
+            $(($param:ident: $kind:ident = $default:literal))?
+            $(-> $output:ident $(($separator:literal))?)?
+        ),+;
+    )+) => {
+        static FAKERS: &[(&str, &[FakerInfo])] = &[$(
+            $(#[$meta])*
+            (stringify!($cat), &[$(
+                $(#[$faker_meta])*
+                FakerInfo {
+                    category: stringify!($cat),
+                    name: stringify!($name),
+                    param: registry!(@param $($param $kind $default)?),
+                    output: registry!(@output $($output)?),
+                    separator: registry!(@separator $($($separator)?)?),
+                },
+            )+]),
+        )+];
+
+        fn generator<L: CityNameGenFn>(category: &str, name: &str) -> Option<Gen<L>> {
+            match category {
+                $(
+                    $(#[$meta])*
+                    stringify!($cat) => match name {
+                        $(
+                            $(#[$faker_meta])*
+                            stringify!($name) => Some(|l, _arg, rng| {
+                                use crate::faker::$cat::raw::$name;
+                                let faker = registry!(@faker $name, l, _arg $(, $kind)?);
+                                registry!(@generate faker, rng $(, $output)?)
+                            }),
+                        )+
+                        _ => None,
+                    },
+                )+
+                _ => None,
+            }
+        }
+    };
+    (@param) => { None };
+    (@param $param:ident $kind:ident $default:literal) => {
+        Some(Param {
+            name: stringify!($param),
+            kind: ParamKind::$kind,
+            default: $default,
+        })
+    };
+    (@output) => { Output::String };
+    (@output $output:ident) => { Output::$output };
+    (@separator) => { " " };
+    (@separator $separator:literal) => { $separator };
+    (@faker $name:ident, $l:ident, $arg:ident) => { $name($l) };
+    (@faker $name:ident, $l:ident, $arg:ident, Range) => { $name($l, $arg.range()) };
+    (@faker $name:ident, $l:ident, $arg:ident, U8) => { $name($l, $arg.u8()) };
+    (@faker $name:ident, $l:ident, $arg:ident, Str) => { $name($l, $arg.str()) };
+    (@generate $faker:ident, $rng:ident) => { Value::String($faker.fake_with_rng($rng)) };
+    (@generate $faker:ident, $rng:ident, Bool) => { Value::Bool($faker.fake_with_rng($rng)) };
+    (@generate $faker:ident, $rng:ident, Float) => { Value::Float($faker.fake_with_rng($rng)) };
+    (@generate $faker:ident, $rng:ident, List) => { Value::List($faker.fake_with_rng($rng)) };
+}
+
+// `name` comes before `job`, so that `Title` is a person's title.
+registry! {
+    name: FirstName, LastName, Title, Suffix, Name, NameWithTitle;
+    address: CityPrefix, CitySuffix, CityName, CountryName, CountryCode, StreetSuffix,
+        StreetName, TimeZone, StateName, StateAbbr, SecondaryAddressType, SecondaryAddress,
+        ZipCode, PostCode, BuildingNumber, Latitude -> Float, Longitude -> Float,
+        Geohash(precision: U8 = "1");
+    barcode: Isbn, Isbn10, Isbn13;
+    boolean: Boolean(ratio: U8 = "50") -> Bool;
+    #[cfg(feature = "random_color")]
+    color: HexColor, RgbColor, RgbaColor, HslColor, HslaColor, Color;
+    #[cfg(feature = "chrono")]
+    chrono: Time, Date, DateTime;
+    creditcard: CreditCardNumber;
+    company: CompanySuffix, CompanyName, Buzzword, BuzzwordMiddle, BuzzwordTail, CatchPhrase,
+        BsVerb, BsAdj, BsNoun, Bs, Profession, Industry;
+    #[cfg(feature = "http")]
+    http: RfcStatusCode, ValidStatusCode;
+    internet: FreeEmailProvider, DomainSuffix, FreeEmail, SafeEmail, Username,
+        Password(len_range: Range = "10..20"), IPv4, IPv6, IP, MACAddress, UserAgent;
+    job: Seniority, Field, Position, Title;
+    lorem: Word, Words(count: Range = "5..10") -> List, Sentence(count: Range = "5..10"),
+        Sentences(count: Range = "5..10") -> List, Paragraph(count: Range = "5..10"),
+        Paragraphs(count: Range = "5..10") -> List("\n");
+    number: Digit, NumberWithFormat(fmt: Str = "###-###-####");
+    phone_number: PhoneNumber, CellNumber;
+    filesystem: #[cfg(feature = "std")] FilePath, FileName, FileExtension,
+        #[cfg(feature = "std")] DirPath, MimeType, Semver, SemverStable, SemverUnstable;
+    currency: CurrencyCode, CurrencyName, CurrencySymbol;
+    finance: Bic, Isin;
+}
+
+/// All the fakers of the registry.
+pub fn fakers() -> impl Iterator<Item = &'static FakerInfo> {
+    FAKERS.iter().flat_map(|(_, fakers)| fakers.iter())
+}
+
+/// Faker `name` of module `category`.
+pub fn get(category: &str, name: &str) -> Option<&'static FakerInfo> {
+    FAKERS
+        .iter()
+        .find(|(c, _)| *c == category)
+        .and_then(|(_, fakers)| fakers.iter().find(|info| info.name == name))
+}
+
+/// Faker named `Name` or `category.Name`.
+pub fn find(key: &str) -> Option<&'static FakerInfo> {
+    match key.split_once('.') {
+        Some((category, name)) => get(category, name),
+        None => fakers().find(|info| info.name == key),
+    }
+}
+
+/// Build faker `key` for `locale`, see [`find`] and [`FakerInfo::build`].
+pub fn build(key: &str, locale: Locale, arg: Option<&str>) -> Result<DynFaker, RegistryError> {
+    find(key)
+        .ok_or_else(|| RegistryError::UnknownFaker(key.to_string()))?
+        .build(locale, arg)
+}
+
+fn parse_range(s: &str) -> Option<Range<usize>> {
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        start.trim().parse().ok()?..end.trim().parse::<usize>().ok()?.checked_add(1)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        start.trim().parse().ok()?..end.trim().parse().ok()?
+    } else {
+        let n: usize = s.trim().parse().ok()?;
+        n..n.checked_add(1)?
+    };
+    (range.start < range.end).then_some(range)
+}
+
+impl FakerInfo {
+    /// Module of the faker in [`faker`](crate::faker), e.g. `address`.
+    pub fn category(&self) -> &'static str {
+        self.category
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn param(&self) -> Option<Param> {
+        self.param
+    }
+
+    pub fn output(&self) -> Output {
+        self.output
+    }
+
+    /// Parse `arg`, or the default argument if `None`.
+    pub(crate) fn parse_arg(&self, arg: Option<&str>) -> Option<Arg> {
+        match (self.param, arg) {
+            (None, None) => Some(Arg::None),
+            (None, Some(_)) => None,
+            (Some(param), arg) => {
+                let arg = arg.unwrap_or(param.default);
+                match param.kind {
+                    ParamKind::Range => parse_range(arg).map(Arg::Range),
+                    ParamKind::U8 => arg.trim().parse().ok().map(Arg::U8),
+                    ParamKind::Str => Some(Arg::Str(arg.to_string())),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn generator<L: CityNameGenFn>(&self) -> Gen<L> {
+        generator(self.category, self.name).expect("registered faker")
+    }
+
+    /// Join lists the way this faker's values are usually displayed.
+    pub(crate) fn value_to_string(&self, value: Value) -> String {
+        match value {
+            Value::String(s) => s,
+            Value::Bool(b) => b.to_string(),
+            Value::Float(f) => f.to_string(),
+            Value::List(items) => items.join(self.separator),
+        }
+    }
+
+    /// Build this faker for `locale` with `arg`, or the default argument.
+    pub fn build(
+        &'static self,
+        locale: Locale,
+        arg: Option<&str>,
+    ) -> Result<DynFaker, RegistryError> {
+        let arg = self
+            .parse_arg(arg)
+            .ok_or_else(|| RegistryError::InvalidArgument {
+                faker: self.name.to_string(),
+                arg: arg.unwrap_or_default().to_string(),
+            })?;
+        Ok(with_locale!(locale, |l| DynFaker::new(self, l, arg)))
+    }
+}
+
+/// Type-erased faker built out of a [`FakerInfo`].
+///
+/// Generates a [`Value`], or its [`String`] form where lists are joined.
+pub struct DynFaker {
+    info: &'static FakerInfo,
+    gen: BoxedGen,
+}
+
+impl DynFaker {
+    fn new<L>(info: &'static FakerInfo, locale: L, arg: Arg) -> Self
+    where
+        L: CityNameGenFn + Send + Sync + 'static,
+    {
+        let gen = info.generator::<L>();
+        DynFaker {
+            info,
+            gen: Box::new(move |rng| gen(locale, &arg, rng)),
+        }
+    }
+
+    pub fn info(&self) -> &'static FakerInfo {
+        self.info
+    }
+}
+
+impl Dummy<DynFaker> for Value {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &DynFaker, rng: &mut R) -> Self {
+        let mut rng = rng;
+        (config.gen)(&mut rng)
+    }
+}
+
+impl Dummy<DynFaker> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &DynFaker, rng: &mut R) -> Self {
+        config
+            .info
+            .value_to_string(Value::dummy_with_rng(config, rng))
+    }
+}
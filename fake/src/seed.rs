@@ -0,0 +1,240 @@
+//! Reproducible seeding for [`Dummy::dummy`](crate::Dummy::dummy) and
+//! [`Fake::fake`](crate::Fake::fake).
+//!
+//! By default the RNG-less methods draw from [`rand::rng()`]. Once a seed is
+//! set for the current thread, they draw from a [`StdRng`] seeded with it
+//! instead, so every `.fake()` call becomes deterministic without passing an
+//! RNG around.
+//!
+//! The seed can be set with [`set_seed`], scoped with [`seeded`], or provided
+//! through the `FAKE_SEED` environment variable, which is read the first time
+//! a thread generates a value.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::{Fake, Faker};
+//!
+//! let a: Vec<u32> = {
+//!     let _guard = fake::seeded(42);
+//!     (0..5).map(|_| Faker.fake()).collect()
+//! };
+//! let b: Vec<u32> = {
+//!     let _guard = fake::seeded(42);
+//!     (0..5).map(|_| Faker.fake()).collect()
+//! };
+//! assert_eq!(a, b);
+//! ```
+//!
+//! Printing the seed when a test fails:
+//!
+//! ```
+//! let seed = fake::set_random_seed();
+//! println!("FAKE_SEED={}", seed);
+//! assert_eq!(fake::current_seed(), Some(seed));
+//! ```
+//!
+//! [`run_seeded`], and the `#[fake::test]` attribute built on it, do this
+//! for a whole test.
+
+use rand::rngs::{StdRng, ThreadRng};
+use rand::{RngCore, SeedableRng};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Name of the environment variable used to seed each thread.
+pub const FAKE_SEED_ENV: &str = "FAKE_SEED";
+
+struct State {
+    init: bool,
+    seeded: Option<(u64, StdRng)>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = const {
+        RefCell::new(State {
+            init: false,
+            seeded: None,
+        })
+    };
+}
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        if !state.init {
+            state.init = true;
+            if let Some(seed) = env_seed() {
+                state.seeded = Some((seed, StdRng::seed_from_u64(seed)));
+            }
+        }
+        f(&mut state)
+    })
+}
+
+fn env_seed() -> Option<u64> {
+    std::env::var(FAKE_SEED_ENV).ok()?.trim().parse().ok()
+}
+
+/// Seed the RNG used by [`Dummy::dummy`](crate::Dummy::dummy) and
+/// [`Fake::fake`](crate::Fake::fake) on the current thread.
+pub fn set_seed(seed: u64) {
+    with_state(|state| state.seeded = Some((seed, StdRng::seed_from_u64(seed))));
+}
+
+/// Pick a random seed, install it with [`set_seed`] and return it.
+pub fn set_random_seed() -> u64 {
+    let seed = rand::rng().next_u64();
+    set_seed(seed);
+    seed
+}
+
+/// Remove the seed of the current thread, falling back to [`rand::rng()`].
+///
+/// The `FAKE_SEED` environment variable is not read again afterwards.
+pub fn clear_seed() {
+    with_state(|state| state.seeded = None);
+}
+
+/// Seed in use on the current thread, if any.
+pub fn current_seed() -> Option<u64> {
+    with_state(|state| state.seeded.as_ref().map(|(seed, _)| *seed))
+}
+
+/// Seed the current thread until the returned guard is dropped.
+///
+/// The previous seed and RNG state are restored when the guard goes out of
+/// scope, so guards can be nested.
+pub fn seeded(seed: u64) -> SeedGuard {
+    let prev = with_state(|state| state.seeded.replace((seed, StdRng::seed_from_u64(seed))));
+    SeedGuard {
+        seed,
+        prev,
+        _not_send: PhantomData,
+    }
+}
+
+/// Guard returned by [`seeded`].
+#[must_use = "the seed is reset as soon as the guard is dropped"]
+pub struct SeedGuard {
+    seed: u64,
+    prev: Option<(u64, StdRng)>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl SeedGuard {
+    /// Seed installed by this guard.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Drop for SeedGuard {
+    fn drop(&mut self) {
+        let prev = self.prev.take();
+        with_state(|state| state.seeded = prev);
+    }
+}
+
+/// Run `test` `runs` times, each time with the current thread seeded with a
+/// new random seed, and print the seed of a run that panics before
+/// resuming the panic.
+///
+/// `test` is given a [`FakeRng`] drawing from the seeded generator, like the
+/// RNG-less methods called inside it. When the `FAKE_SEED` environment
+/// variable is set, `test` runs once with that seed instead, replaying the
+/// failing run.
+///
+/// This is what the `#[fake::test]` attribute expands to.
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::name::en::Name;
+/// use fake::Fake;
+///
+/// fake::seed::run_seeded(10, |rng| {
+///     let name: String = Name().fake_with_rng(rng);
+///     assert!(name.contains(' '));
+/// });
+/// ```
+pub fn run_seeded<F: FnMut(&mut FakeRng)>(runs: u32, mut test: F) {
+    let replay = env_seed();
+    let runs = if replay.is_some() { 1 } else { runs };
+    for run in 1..=runs {
+        let seed = replay.unwrap_or_else(|| rand::rng().next_u64());
+        let _guard = seeded(seed);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| test(&mut FakeRng::new())));
+        if let Err(payload) = result {
+            eprintln!(
+                "run {}/{} failed with seed {}, replay it with {}={}",
+                run, runs, seed, FAKE_SEED_ENV, seed
+            );
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// RNG handed to [`Dummy::dummy_with_rng`](crate::Dummy::dummy_with_rng)
+/// by the RNG-less methods.
+///
+/// Draws from the seeded thread-local generator if one is installed and from
+/// [`rand::rng()`] otherwise. The thread-local state is only borrowed for the
+/// duration of each draw, so nested generation is fine.
+pub struct FakeRng(Inner);
+
+enum Inner {
+    Seeded,
+    Thread(ThreadRng),
+}
+
+impl FakeRng {
+    pub fn new() -> Self {
+        if current_seed().is_some() {
+            FakeRng(Inner::Seeded)
+        } else {
+            FakeRng(Inner::Thread(rand::rng()))
+        }
+    }
+
+    fn seeded<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+        with_state(|state| match state.seeded.as_mut() {
+            Some((_, rng)) => f(rng),
+            // the seed was cleared while this handle was alive
+            None => f(&mut StdRng::from_rng(&mut rand::rng())),
+        })
+    }
+}
+
+impl Default for FakeRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RngCore for FakeRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        match &mut self.0 {
+            Inner::Seeded => Self::seeded(|rng| rng.next_u32()),
+            Inner::Thread(rng) => rng.next_u32(),
+        }
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        match &mut self.0 {
+            Inner::Seeded => Self::seeded(|rng| rng.next_u64()),
+            Inner::Thread(rng) => rng.next_u64(),
+        }
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match &mut self.0 {
+            Inner::Seeded => Self::seeded(|rng| rng.fill_bytes(dest)),
+            Inner::Thread(rng) => rng.fill_bytes(dest),
+        }
+    }
+}
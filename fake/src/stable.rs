@@ -0,0 +1,434 @@
+//! Value streams pinned across releases, for snapshot tests.
+//!
+//! Seeding an RNG makes generation reproducible, but only as long as nothing
+//! changes in between: a faker drawing one more number, or a `rand` upgrade
+//! changing how `choose` or `random_range` use the RNG, changes every value
+//! that follows. In stable mode, the fakers of [`faker`](crate::faker) and
+//! the ranges of primitive types sample with the algorithms below instead of
+//! the ones of `rand`. Together with [`StableRng`], the values generated for
+//! a seed are part of the public API: they only change in a major release.
+//!
+//! Stable mode is enabled per thread by the guard returned by [`enable`].
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::faker::name::en::Name;
+//! use fake::stable::{self, StableRng};
+//! use fake::Fake;
+//! use rand::SeedableRng;
+//!
+//! let _stable = stable::enable();
+//! let mut rng = StableRng::seed_from_u64(42);
+//! let name: String = Name().fake_with_rng(&mut rng);
+//! # let _ = name;
+//! ```
+//!
+//! # Algorithms
+//!
+//! - [`StableRng`] is xoshiro256\*\*. [`seed_from_u64`](SeedableRng::seed_from_u64)
+//!   fills its state with four outputs of SplitMix64, and
+//!   [`from_seed`](SeedableRng::from_seed) reads it as four little-endian
+//!   `u64`s. `next_u32` is the upper half of `next_u64`, and `fill_bytes`
+//!   writes `next_u64` in little-endian, dropping the bytes left over.
+//! - An integer in `0..=max` is drawn from `u64`s with Lemire's
+//!   multiply-and-reject method: `x * (max + 1)` on 128 bits, rejecting `x`
+//!   while the low half is below `2^64 mod (max + 1)`, and keeping the high
+//!   half. `max = u64::MAX` takes one `u64` as is. Beyond 64 bits, two `u64`s
+//!   make a `u128`, high half first, masked to the bit length of `max` and
+//!   rejected while above it.
+//! - A range of integers `a..=b` of any type is `a + n`, `n` in `0..=b - a`.
+//!   `a..b` is `a..=b - 1`, other ranges use the bounds of the type.
+//! - A float in `0..1` is the top 53 bits of a `u64` times `2^-53` for `f64`,
+//!   the top 24 bits times `2^-24` for `f32`. A range of floats is
+//!   `a + (b - a) * x`.
+//! - A `bool` is the top bit of a `u64`. A `char` is drawn in
+//!   `0..=0x10FFFF - 0x800`, and moved past the surrogates from `0xD800`.
+//! - Picking in a slice takes the index in `0..=len - 1`. Picking `n` items
+//!   is a partial Fisher-Yates shuffle of the indices: the index at position
+//!   `i` is swapped with the one at `i + k`, `k` in `0..=len - 1 - i`, for
+//!   each `i` in `0..n`.
+//! - A type deriving `Dummy` with `#[dummy(stable_fields)]` draws one `u64`
+//!   seed, and generates each field with
+//!   `StableRng::seed_from_u64(seed ^ h)`, `h` being the 64-bit FNV-1a hash
+//!   of the field name, or of its index for tuple fields.
+//!
+//! `Regex`, with the `regex` feature, and the uniform sampling of
+//! [`Pool`](crate::pool::Pool) are covered as well. Not covered are the fakers
+//! relying on other crates, like colors, the configs of
+//! [`utils`](crate::utils) and [`distribution`](crate::distribution), and
+//! `Pool` sampling with weights or a Zipf skew.
+
+use alloc::vec::{self, Vec};
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use rand::{Rng, RngCore, SeedableRng};
+
+/// Pinned xoshiro256\*\* generator, see the [module](self) docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StableRng {
+    s: [u64; 4],
+}
+
+impl RngCore for StableRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+impl SeedableRng for StableRng {
+    type Seed = [u8; 32];
+
+    /// Panics if the seed is all zeros, which xoshiro can't start from.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut s = [0; 4];
+        for (s, bytes) in s.iter_mut().zip(seed.chunks_exact(8)) {
+            *s = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        assert!(s != [0; 4], "StableRng needs a non-zero seed");
+        StableRng { s }
+    }
+
+    fn seed_from_u64(mut state: u64) -> Self {
+        let mut splitmix = || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        StableRng {
+            s: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static ENABLED: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Number of live guards on all threads, sparing the thread-local lookup
+/// while stable mode is not used at all.
+#[cfg(feature = "std")]
+static GUARDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Enable stable mode on the current thread until the returned guard is
+/// dropped.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn enable() -> StableGuard {
+    GUARDS.fetch_add(1, Ordering::Relaxed);
+    let prev = ENABLED.with(|enabled| enabled.replace(true));
+    StableGuard {
+        prev,
+        _not_send: core::marker::PhantomData,
+    }
+}
+
+/// Whether stable mode is enabled on the current thread. Always `false`
+/// without the `std` feature.
+#[inline]
+pub fn is_enabled() -> bool {
+    #[cfg(feature = "std")]
+    return GUARDS.load(Ordering::Relaxed) != 0 && ENABLED.with(|enabled| enabled.get());
+    #[cfg(not(feature = "std"))]
+    false
+}
+
+/// Guard returned by [`enable`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use = "stable mode is disabled as soon as the guard is dropped"]
+pub struct StableGuard {
+    prev: bool,
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for StableGuard {
+    fn drop(&mut self) {
+        ENABLED.with(|enabled| enabled.set(self.prev));
+        GUARDS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// RNG of the field `name` of a type deriving `Dummy` with
+/// `#[dummy(stable_fields)]`, given the seed drawn for the whole value.
+#[doc(hidden)]
+pub fn field_rng(seed: u64, name: &str) -> StableRng {
+    // FNV-1a
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    StableRng::seed_from_u64(seed ^ hash)
+}
+
+/// Integer in `0..=max`.
+fn below_or_eq<R: RngCore + ?Sized>(rng: &mut R, max: u64) -> u64 {
+    if max == u64::MAX {
+        return rng.next_u64();
+    }
+    let n = max + 1;
+    let threshold = n.wrapping_neg() % n;
+    loop {
+        let m = u128::from(rng.next_u64()) * u128::from(n);
+        if m as u64 >= threshold {
+            return (m >> 64) as u64;
+        }
+    }
+}
+
+/// Integer in `0..=max`, for 128-bit types.
+fn below_or_eq_u128<R: RngCore + ?Sized>(rng: &mut R, max: u128) -> u128 {
+    if let Ok(max) = u64::try_from(max) {
+        return below_or_eq(rng, max).into();
+    }
+    let mask = u128::MAX >> max.leading_zeros();
+    loop {
+        let high = u128::from(rng.next_u64());
+        let x = (high << 64 | u128::from(rng.next_u64())) & mask;
+        if x <= max {
+            return x;
+        }
+    }
+}
+
+/// Primitive types generated by [`Faker`](crate::Faker) with the pinned
+/// algorithms.
+pub(crate) trait Sample: Sized {
+    fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self;
+}
+
+/// Primitive types sampled in ranges with the pinned algorithms.
+pub(crate) trait SampleRange: Sample {
+    /// Value in `low..=high`.
+    fn between<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self;
+
+    /// Value in `low..high`.
+    fn below<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self;
+}
+
+macro_rules! sample_int {
+    ($($typ:ty => $unsigned:ty, $below_or_eq:ident;)+) => {
+        $(
+            impl Sample for $typ {
+                fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                    Self::between(<$typ>::MIN, <$typ>::MAX, rng)
+                }
+            }
+
+            impl SampleRange for $typ {
+                fn between<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+                    assert!(low <= high, "cannot sample empty range");
+                    let span = high.wrapping_sub(low) as $unsigned;
+                    low.wrapping_add($below_or_eq(rng, span as _) as $typ)
+                }
+
+                fn below<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+                    assert!(low < high, "cannot sample empty range");
+                    Self::between(low, high - 1, rng)
+                }
+            }
+        )+
+    };
+}
+
+sample_int! {
+    u8 => u8, below_or_eq;
+    u16 => u16, below_or_eq;
+    u32 => u32, below_or_eq;
+    u64 => u64, below_or_eq;
+    u128 => u128, below_or_eq_u128;
+    i8 => u8, below_or_eq;
+    i16 => u16, below_or_eq;
+    i32 => u32, below_or_eq;
+    i64 => u64, below_or_eq;
+    i128 => u128, below_or_eq_u128;
+}
+
+impl Sample for usize {
+    fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        u64::any(rng) as usize
+    }
+}
+
+impl SampleRange for usize {
+    fn between<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        u64::between(low as u64, high as u64, rng) as usize
+    }
+
+    fn below<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        u64::below(low as u64, high as u64, rng) as usize
+    }
+}
+
+impl Sample for isize {
+    fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        i64::any(rng) as isize
+    }
+}
+
+impl SampleRange for isize {
+    fn between<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        i64::between(low as i64, high as i64, rng) as isize
+    }
+
+    fn below<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        i64::below(low as i64, high as i64, rng) as isize
+    }
+}
+
+macro_rules! sample_float {
+    ($($typ:ty, $bits:expr;)+) => {
+        $(
+            impl Sample for $typ {
+                fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                    (rng.next_u64() >> (64 - $bits)) as $typ / (1u64 << $bits) as $typ
+                }
+            }
+
+            impl SampleRange for $typ {
+                fn between<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+                    low + (high - low) * Self::any(rng)
+                }
+
+                fn below<R: RngCore + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+                    Self::between(low, high, rng)
+                }
+            }
+        )+
+    };
+}
+
+sample_float! {
+    f32, 24;
+    f64, 53;
+}
+
+impl Sample for () {
+    fn any<R: RngCore + ?Sized>(_: &mut R) -> Self {}
+}
+
+impl Sample for bool {
+    fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        rng.next_u64() >> 63 == 1
+    }
+}
+
+impl Sample for char {
+    fn any<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let n = u32::between(0, char::MAX as u32 - 0x800, rng);
+        char::from_u32(if n >= 0xD800 { n + 0x800 } else { n }).unwrap()
+    }
+}
+
+/// Drop-in for [`rand::seq::IndexedRandom`] following stable mode.
+pub(crate) trait IndexedRandom {
+    type Item;
+
+    fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&Self::Item>;
+
+    fn choose_multiple<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> ChooseMultiple<'_, Self::Item>;
+}
+
+impl<T> IndexedRandom for [T] {
+    type Item = T;
+
+    #[inline]
+    fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if !is_enabled() {
+            return rand::seq::IndexedRandom::choose(self, rng);
+        }
+        if self.is_empty() {
+            return None;
+        }
+        Some(&self[usize::between(0, self.len() - 1, rng)])
+    }
+
+    fn choose_multiple<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> ChooseMultiple<'_, T> {
+        if !is_enabled() {
+            return ChooseMultiple::Rand(rand::seq::IndexedRandom::choose_multiple(
+                self, rng, amount,
+            ));
+        }
+        let amount = amount.min(self.len());
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        for i in 0..amount {
+            let j = i + usize::between(0, self.len() - 1 - i, rng);
+            indices.swap(i, j);
+        }
+        indices.truncate(amount);
+        ChooseMultiple::Stable(self, indices.into_iter())
+    }
+}
+
+/// Iterator returned by [`IndexedRandom::choose_multiple`].
+pub(crate) enum ChooseMultiple<'a, T> {
+    Rand(rand::seq::SliceChooseIter<'a, [T], T>),
+    Stable(&'a [T], vec::IntoIter<usize>),
+}
+
+impl<'a, T> Iterator for ChooseMultiple<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self {
+            ChooseMultiple::Rand(iter) => iter.next(),
+            ChooseMultiple::Stable(slice, indices) => indices.next().map(|i| &slice[i]),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ChooseMultiple::Rand(iter) => iter.size_hint(),
+            ChooseMultiple::Stable(_, indices) => indices.size_hint(),
+        }
+    }
+}
+
+/// Drop-in for [`rand::seq::IteratorRandom`] following stable mode, for
+/// iterators of known length.
+pub(crate) trait IteratorRandom: ExactSizeIterator + Sized {
+    fn choose<R: Rng + ?Sized>(mut self, rng: &mut R) -> Option<Self::Item> {
+        if !is_enabled() {
+            return rand::seq::IteratorRandom::choose(self, rng);
+        }
+        match self.len() {
+            0 => None,
+            len => self.nth(usize::between(0, len - 1, rng)),
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> IteratorRandom for I {}
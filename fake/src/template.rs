@@ -0,0 +1,219 @@
+//! String templates combining locale fakers.
+//!
+//! A [`Template`] is parsed once and expands every `{Placeholder}` with the
+//! faker of the same name from the [`registry`](crate::registry), in the
+//! locale of the template.
+//!
+//! - `{FirstName}` uses `faker::name::FirstName`. A faker can be qualified with
+//!   its module like `{job.Title}`, unqualified `{Title}` is `name.Title`.
+//! - `{Words:3..5}` passes an argument: a range for `Words`, `Sentence`,
+//!   `Sentences`, `Paragraph`, `Paragraphs` and `Password`, a number for
+//!   `Geohash` and `Boolean`, a format for `NumberWithFormat`. `Words` and
+//!   `Sentences` are joined with spaces, `Paragraphs` with new lines.
+//! - `{LastName|lower}` applies the `lower`, `upper` or `capitalize` filters,
+//!   left to right.
+//! - `{{` and `}}` are literal braces.
+//!
+//! # Examples
+//!
+//! ```
+//! use fake::locales::FR_FR;
+//! use fake::{Fake, Template};
+//!
+//! let email = Template::parse("{FirstName|lower}.{LastName|lower}@acme.{DomainSuffix}");
+//! let address: String = email.unwrap().fake();
+//!
+//! let bio = Template::parse("{Name} - {job.Title} ({Words:2..4})")
+//!     .unwrap()
+//!     .locale(FR_FR);
+//! let bio: String = bio.fake();
+//! ```
+
+use crate::faker::impls::address::CityNameGenFn;
+use crate::locales::EN;
+use crate::registry::{self, Arg, FakerInfo, Gen};
+use crate::Dummy;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use rand::Rng;
+
+/// Faker expanding a parsed template, see the [module](self) docs.
+pub struct Template<L = EN> {
+    parts: Vec<Part<L>>,
+    locale: L,
+}
+
+enum Part<L> {
+    Text(String),
+    Field(Field<L>),
+}
+
+struct Field<L> {
+    info: &'static FakerInfo,
+    arg: Arg,
+    filters: Vec<Filter>,
+    gen: Gen<L>,
+}
+
+#[derive(Clone, Copy)]
+enum Filter {
+    Lower,
+    Upper,
+    Capitalize,
+}
+
+impl Filter {
+    fn apply(self, s: String) -> String {
+        match self {
+            Filter::Lower => s.to_lowercase(),
+            Filter::Upper => s.to_uppercase(),
+            Filter::Capitalize => {
+                let mut chars = s.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => s,
+                }
+            }
+        }
+    }
+}
+
+/// Error returned by [`Template::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A `{` at this byte offset is never closed.
+    Unclosed(usize),
+    /// A lone `}` at this byte offset.
+    Unopened(usize),
+    /// No faker with this name.
+    UnknownFaker(String),
+    /// The argument is missing, unexpected or malformed for this faker.
+    InvalidArgument { faker: String, arg: String },
+    /// No filter with this name.
+    UnknownFilter(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Unclosed(at) => write!(f, "unclosed `{{` at {}", at),
+            TemplateError::Unopened(at) => write!(f, "unexpected `}}` at {}", at),
+            TemplateError::UnknownFaker(name) => write!(f, "unknown faker `{}`", name),
+            TemplateError::InvalidArgument { faker, arg } => {
+                write!(f, "invalid argument `{}` for faker `{}`", arg, faker)
+            }
+            TemplateError::UnknownFilter(name) => write!(f, "unknown filter `{}`", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemplateError {}
+
+fn parse_field<L: CityNameGenFn>(src: &str) -> Result<Field<L>, TemplateError> {
+    let mut segments = src.split('|');
+    let head = segments.next().unwrap_or_default();
+    let (key, arg) = match head.split_once(':') {
+        Some((key, arg)) => (key.trim(), Some(arg)),
+        None => (head.trim(), None),
+    };
+    let info = registry::find(key).ok_or_else(|| TemplateError::UnknownFaker(key.to_string()))?;
+    // unlike the registry, templates have no default arguments
+    let parsed = match (info.param(), arg) {
+        (Some(_), None) => None,
+        _ => info.parse_arg(arg),
+    };
+    let arg = parsed.ok_or_else(|| TemplateError::InvalidArgument {
+        faker: key.to_string(),
+        arg: arg.unwrap_or_default().to_string(),
+    })?;
+
+    let filters = segments
+        .map(|filter| match filter.trim() {
+            "lower" => Ok(Filter::Lower),
+            "upper" => Ok(Filter::Upper),
+            "capitalize" => Ok(Filter::Capitalize),
+            other => Err(TemplateError::UnknownFilter(other.to_string())),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Field {
+        info,
+        arg,
+        filters,
+        gen: info.generator(),
+    })
+}
+
+impl Template<EN> {
+    /// Parse `template`, using the [`EN`] locale.
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut rest = template.char_indices().peekable();
+        while let Some((at, c)) = rest.next() {
+            match c {
+                '{' if rest.next_if(|&(_, c)| c == '{').is_some() => text.push('{'),
+                '}' if rest.next_if(|&(_, c)| c == '}').is_some() => text.push('}'),
+                '}' => return Err(TemplateError::Unopened(at)),
+                '{' => {
+                    let len = template[at + 1..]
+                        .find('}')
+                        .ok_or(TemplateError::Unclosed(at))?;
+                    if !text.is_empty() {
+                        parts.push(Part::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Field(parse_field(&template[at + 1..at + 1 + len])?));
+                    while rest.next_if(|&(i, _)| i <= at + 1 + len).is_some() {}
+                }
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Template { parts, locale: EN })
+    }
+}
+
+impl<L: CityNameGenFn> Template<L> {
+    /// Expand the placeholders with the fakers of `locale` instead.
+    pub fn locale<L2: CityNameGenFn>(self, locale: L2) -> Template<L2> {
+        let parts = self
+            .parts
+            .into_iter()
+            .map(|part| match part {
+                Part::Text(text) => Part::Text(text),
+                Part::Field(field) => Part::Field(Field {
+                    info: field.info,
+                    arg: field.arg,
+                    filters: field.filters,
+                    gen: field.info.generator(),
+                }),
+            })
+            .collect();
+        Template { parts, locale }
+    }
+}
+
+impl<L: CityNameGenFn> Dummy<Template<L>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Template<L>, rng: &mut R) -> Self {
+        let mut rng = rng;
+        let mut out = String::new();
+        for part in &config.parts {
+            match part {
+                Part::Text(text) => out.push_str(text),
+                Part::Field(field) => {
+                    let value = (field.gen)(config.locale, &field.arg, &mut rng);
+                    let value = field.info.value_to_string(value);
+                    let value = field.filters.iter().fold(value, |s, f| f.apply(s));
+                    out.push_str(&value);
+                }
+            }
+        }
+        out
+    }
+}
@@ -1,4 +1,7 @@
 use crate::{Dummy, Fake, Faker};
+use alloc::{string::String, vec::Vec};
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
 use rand::Rng;
 
 pub trait IntoInner {
@@ -11,6 +14,15 @@ pub struct EitherFaker<A, B> {
     pub b: B,
 }
 
+/// Value of any type `T` generated by a faker wrapping other fakers.
+///
+/// Such fakers, like [`OneOf`], [`Choice`], [`Pool`](crate::pool::Pool),
+/// [`Unique`](crate::Unique) and the [combinators](crate::combinator), can't
+/// implement `Dummy` for every `T` without overlapping the impls for
+/// `Option`, arrays and smart pointers. They generate the primitive types,
+/// [`String`] and `&str` directly, some of them [`Vec`] too, and any other
+/// `T` as a `WrappedVal<T>`, e.g. with `wrapper = "WrappedVal"` in
+/// `#[derive(Dummy)]`.
 pub struct WrappedVal<T>(pub T);
 impl<T> WrappedVal<T> {
     pub fn new(val: T) -> Self {
@@ -42,6 +54,265 @@ pub fn either<A, B>(a: A, b: B) -> EitherFaker<A, B> {
     EitherFaker { a, b }
 }
 
+/// Faker picking one of several fakers, uniformly or by weight.
+///
+/// The fakers are given as a tuple of up to 8 configs. Unlike
+/// [`EitherFaker`], it only needs [`WrappedVal`] for some output types.
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::phone_number::en::{CellNumber, PhoneNumber};
+/// use fake::utils::OneOf;
+/// use fake::{one_of, Dummy, Fake, Faker};
+///
+/// let number: String = OneOf::new((CellNumber(), PhoneNumber())).fake();
+/// let n: u32 = OneOf::new((0..10, 100..110, 1000..1010))
+///     .weights([1, 2, 7])
+///     .fake();
+///
+/// #[derive(Debug, Dummy)]
+/// struct Contact {
+///     // 70% cell number, 30% landline
+///     #[dummy(faker = "one_of![70 => CellNumber(), 30 => PhoneNumber()]")]
+///     phone: String,
+/// }
+/// let contact: Contact = Faker.fake();
+/// ```
+pub struct OneOf<C> {
+    fakers: C,
+    weights: Option<WeightedIndex<f64>>,
+}
+
+impl<C> OneOf<C> {
+    pub fn new(fakers: C) -> Self {
+        OneOf {
+            fakers,
+            weights: None,
+        }
+    }
+
+    fn index<R: Rng + ?Sized>(&self, rng: &mut R) -> usize
+    where
+        C: FakerTuple,
+    {
+        sample_index(&self.weights, C::LEN, rng)
+    }
+}
+
+impl<C: FakerTuple> OneOf<C> {
+    /// Pick fakers proportionally to `weights`, one weight per faker.
+    ///
+    /// Panics if the number of weights differs from the number of fakers,
+    /// or if the weights are negative or all zero.
+    pub fn weights<I>(mut self, weights: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Weight,
+    {
+        self.weights = Some(weighted_index(weights, C::LEN));
+        self
+    }
+}
+
+/// Tuple of fakers accepted by [`OneOf`].
+pub trait FakerTuple {
+    /// Number of fakers.
+    const LEN: usize;
+}
+
+/// Weight accepted by [`OneOf::weights`], [`Choice::weights`] and
+/// [`one_of!`](crate::one_of): any primitive number.
+pub trait Weight {
+    fn into_weight(self) -> f64;
+}
+
+macro_rules! weight_impl {
+    ($($typ:ty),*) => {
+        $(
+            impl Weight for $typ {
+                #[inline]
+                fn into_weight(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+weight_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Faker picking one value out of a slice, uniformly or by weight.
+///
+/// # Examples
+///
+/// ```
+/// use fake::utils::Choice;
+/// use fake::Fake;
+///
+/// let color: &str = Choice::new(&["red", "green", "blue"]).fake();
+/// let size: String = Choice::new(&["S", "M", "L"]).weights([1.0, 2.5, 1.0]).fake();
+/// let qty: u8 = Choice::new(&[1, 5, 10]).fake();
+/// ```
+pub struct Choice<'a, T> {
+    items: &'a [T],
+    weights: Option<WeightedIndex<f64>>,
+}
+
+impl<'a, T> Choice<'a, T> {
+    pub fn new(items: &'a [T]) -> Self {
+        Choice {
+            items,
+            weights: None,
+        }
+    }
+
+    /// Pick items proportionally to `weights`, one weight per item.
+    ///
+    /// Panics if the number of weights differs from the number of items,
+    /// or if the weights are negative or all zero.
+    pub fn weights<I>(mut self, weights: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Weight,
+    {
+        self.weights = Some(weighted_index(weights, self.items.len()));
+        self
+    }
+
+    fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> &'a T {
+        assert!(!self.items.is_empty(), "Choice needs at least one item");
+        &self.items[sample_index(&self.weights, self.items.len(), rng)]
+    }
+}
+
+fn weighted_index<I>(weights: I, len: usize) -> WeightedIndex<f64>
+where
+    I: IntoIterator,
+    I::Item: Weight,
+{
+    let weights: Vec<f64> = weights.into_iter().map(Weight::into_weight).collect();
+    assert_eq!(
+        weights.len(),
+        len,
+        "expected one weight per choice, got {} weights for {} choices",
+        weights.len(),
+        len
+    );
+    WeightedIndex::new(&weights).expect("Can sample weighted")
+}
+
+fn sample_index<R: Rng + ?Sized>(
+    weights: &Option<WeightedIndex<f64>>,
+    len: usize,
+    rng: &mut R,
+) -> usize {
+    match weights {
+        Some(dist) => dist.sample(rng),
+        None => rng.random_range(0..len),
+    }
+}
+
+macro_rules! one_of_impl {
+    (@arity [$($gen:tt)*] $typ:ty => $inner:ty, $wrap:expr; $(($idx:tt) $F:ident)+) => {
+        impl<$($gen)* $($F),+> Dummy<OneOf<($($F,)+)>> for $typ
+        where
+            $($inner: Dummy<$F>),+
+        {
+            fn dummy_with_rng<R: Rng + ?Sized>(config: &OneOf<($($F,)+)>, rng: &mut R) -> Self {
+                match config.index(rng) {
+                    $($idx => $wrap(config.fakers.$idx.fake_with_rng::<$inner, _>(rng)),)+
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+    (@len $len:expr; $($F:ident)+) => {
+        impl<$($F),+> FakerTuple for ($($F,)+) {
+            const LEN: usize = $len;
+        }
+    };
+    ([$($gen:tt)*] $typ:ty => $inner:ty, $wrap:expr) => {
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1 (2) F2);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1 (2) F2 (3) F3);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1 (2) F2 (3) F3 (4) F4);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1 (2) F2 (3) F3 (4) F4 (5) F5);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1 (2) F2 (3) F3 (4) F4 (5) F5 (6) F6);
+        one_of_impl!(@arity [$($gen)*] $typ => $inner, $wrap; (0) F0 (1) F1 (2) F2 (3) F3 (4) F4 (5) F5 (6) F6 (7) F7);
+    };
+}
+
+macro_rules! choice_impl {
+    ($($typ:ty),*) => {
+        $(
+            one_of_impl!([] $typ => $typ, core::convert::identity);
+
+            impl Dummy<Choice<'_, $typ>> for $typ {
+                fn dummy_with_rng<R: Rng + ?Sized>(config: &Choice<'_, $typ>, rng: &mut R) -> Self {
+                    config.choose(rng).clone()
+                }
+            }
+        )*
+    };
+}
+
+choice_impl!(
+    String, bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+one_of_impl!(['a,] &'a str => &'a str, core::convert::identity);
+one_of_impl!([T,] WrappedVal<T> => T, WrappedVal::new);
+one_of_impl!(@len 1; F0);
+one_of_impl!(@len 2; F0 F1);
+one_of_impl!(@len 3; F0 F1 F2);
+one_of_impl!(@len 4; F0 F1 F2 F3);
+one_of_impl!(@len 5; F0 F1 F2 F3 F4);
+one_of_impl!(@len 6; F0 F1 F2 F3 F4 F5);
+one_of_impl!(@len 7; F0 F1 F2 F3 F4 F5 F6);
+one_of_impl!(@len 8; F0 F1 F2 F3 F4 F5 F6 F7);
+
+impl<'a> Dummy<Choice<'_, &'a str>> for &'a str {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Choice<'_, &'a str>, rng: &mut R) -> Self {
+        config.choose(rng)
+    }
+}
+
+impl Dummy<Choice<'_, &str>> for String {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Choice<'_, &str>, rng: &mut R) -> Self {
+        (*config.choose(rng)).into()
+    }
+}
+
+impl<T: Clone> Dummy<Choice<'_, T>> for WrappedVal<T> {
+    fn dummy_with_rng<R: Rng + ?Sized>(config: &Choice<'_, T>, rng: &mut R) -> Self {
+        WrappedVal::new(config.choose(rng).clone())
+    }
+}
+
+/// Creates a [`OneOf`] faker, optionally weighted.
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::name::en::{FirstName, LastName};
+/// use fake::{one_of, Fake};
+///
+/// let a: String = one_of![FirstName(), LastName()].fake();
+/// let b: String = one_of![3 => FirstName(), 1 => LastName()].fake();
+/// let c: u8 = one_of![0.9 => 0..10, 0.1 => 200..=255].fake();
+/// let d: u8 = one_of![9u64 => 0..10, 1usize => 200..=255].fake();
+/// ```
+#[macro_export]
+macro_rules! one_of {
+    ($($w:expr => $f:expr),+ $(,)?) => {
+        $crate::utils::OneOf::new(($($f,)+)).weights([$($crate::utils::Weight::into_weight($w)),+])
+    };
+    ($($f:expr),+ $(,)?) => {
+        $crate::utils::OneOf::new(($($f,)+))
+    };
+}
+
 #[cfg(feature = "always-true-rng")]
 mod always_true_rng {
     use rand::{rngs::mock::StepRng, RngCore};
@@ -0,0 +1,137 @@
+use crate::Fake;
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+use deunicode::deunicode_char;
+use rand::Rng;
+
+/// Generate a fake string straight into a caller-owned buffer.
+///
+/// Implemented by the built-in string fakers, e.g. names, addresses, lorem
+/// text or emails. Their `Dummy<..> for String` impls allocate a new
+/// [`String`] per value, while `fake_into` appends to `w` and only allocates
+/// to grow it, which matters when generating millions of rows.
+///
+/// # Examples
+///
+/// ```
+/// use fake::faker::internet::en::SafeEmail;
+/// use fake::faker::lorem::en::Sentence;
+/// use fake::FakeWrite;
+///
+/// let mut rng = rand::rng();
+/// let mut buf = String::new();
+/// for _ in 0..3 {
+///     buf.clear();
+///     Sentence(3..5).fake_into(&mut buf, &mut rng).unwrap();
+///     buf.push(',');
+///     SafeEmail().fake_into(&mut buf, &mut rng).unwrap();
+///     assert!(buf.ends_with(".com") || buf.ends_with(".net") || buf.ends_with(".org"));
+/// }
+/// ```
+pub trait FakeWrite {
+    /// Append a fake value to `w`, drawing from `rng`.
+    ///
+    /// Given the same RNG, writes exactly what the `String` impl generates.
+    fn fake_into<W: Write + ?Sized, R: Rng + ?Sized>(&self, w: &mut W, rng: &mut R) -> fmt::Result;
+}
+
+/// Generate a `String` with `faker`, for the `Dummy` impls wrapping
+/// [`FakeWrite`].
+pub(crate) fn fake_string<C: FakeWrite, R: Rng + ?Sized>(faker: &C, rng: &mut R) -> String {
+    let mut s = String::new();
+    faker
+        .fake_into(&mut s, rng)
+        .expect("a Display implementation returned an error unexpectedly");
+    s
+}
+
+/// Write `template` with each placeholder replaced by its value, like
+/// chained [`str::replace`] calls. Values are generated by the caller in the
+/// order of the `replace` calls, so that both use the RNG the same way.
+pub(crate) fn write_template<W: Write + ?Sized>(
+    w: &mut W,
+    template: &str,
+    values: &[(&str, &dyn Display)],
+) -> fmt::Result {
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        w.write_str(&rest[..start])?;
+        rest = &rest[start..];
+        match values.iter().find(|(key, _)| rest.starts_with(key)) {
+            Some((key, value)) => {
+                write!(w, "{}", value)?;
+                rest = &rest[key.len()..];
+            }
+            None => {
+                w.write_char('{')?;
+                rest = &rest[1..];
+            }
+        }
+    }
+    w.write_str(rest)
+}
+
+/// Write `format` with each `#` replaced by a digit and each `^` by a
+/// non-zero digit.
+pub(crate) fn write_numerified<W: Write + ?Sized, R: Rng + ?Sized>(
+    w: &mut W,
+    format: &str,
+    rng: &mut R,
+) -> fmt::Result {
+    for c in format.chars() {
+        let c = match c {
+            '^' => char::from_digit((1..10).fake_with_rng::<u32, _>(rng), 10).unwrap(),
+            '#' => char::from_digit((0..10).fake_with_rng::<u32, _>(rng), 10).unwrap(),
+            other => other,
+        };
+        w.write_char(c)?;
+    }
+    Ok(())
+}
+
+/// Writer lowercasing what goes through it.
+pub(crate) struct Lowercase<'a, W: ?Sized>(pub &'a mut W);
+
+impl<W: Write + ?Sized> Write for Lowercase<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars()
+            .flat_map(char::to_lowercase)
+            .try_for_each(|c| self.0.write_char(c))
+    }
+}
+
+/// Writer transliterating what goes through it to ASCII, like
+/// [`AsciiChars`](deunicode::AsciiChars) does for a whole string.
+pub(crate) struct Ascii<'a, W: ?Sized> {
+    w: &'a mut W,
+    space: bool,
+}
+
+impl<'a, W: ?Sized> Ascii<'a, W> {
+    pub(crate) fn new(w: &'a mut W) -> Self {
+        Ascii { w, space: false }
+    }
+}
+
+impl<W: Write + ?Sized> Write for Ascii<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let ascii = deunicode_char(c);
+            // the trailing space of a transliteration is dropped before a
+            // space and at the end, so it's only written once the next
+            // character is known
+            if core::mem::take(&mut self.space) && !ascii.map_or(false, |a| a.starts_with(' ')) {
+                self.w.write_char(' ')?;
+            }
+            match ascii {
+                Some(a) if a.len() > 1 && a.ends_with(' ') => {
+                    self.w.write_str(&a[..a.len() - 1])?;
+                    self.space = true;
+                }
+                Some(a) => self.w.write_str(a)?,
+                None => self.w.write_char('\u{FFFD}')?,
+            }
+        }
+        Ok(())
+    }
+}
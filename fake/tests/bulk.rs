@@ -0,0 +1,59 @@
+use fake::bulk;
+use fake::faker::name::en::Name;
+use fake::{Dummy, Faker};
+
+#[derive(Debug, PartialEq, Dummy)]
+struct Row {
+    id: u64,
+    #[dummy(faker = "Name()")]
+    name: String,
+    tags: Vec<u8>,
+}
+
+#[test]
+fn items_are_regenerable_by_index() {
+    let rows: Vec<Row> = bulk::generate(&Faker, 50, 9);
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(*row, bulk::generate_at(&Faker, 9, i as u64));
+    }
+    let other: Vec<Row> = bulk::generate(&Faker, 50, 10);
+    assert_ne!(rows, other);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_generate_ignores_thread_count() {
+    let expected: Vec<Row> = bulk::generate(&Faker, 2_000, 3);
+    for threads in [1, 3, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let rows: Vec<Row> = pool.install(|| fake::par_generate(&Faker, 2_000, 3));
+        assert_eq!(rows, expected);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_generate_in_stable_mode() {
+    let default: Vec<Row> = bulk::generate(&Faker, 500, 3);
+    let _stable = fake::stable::enable();
+    let expected: Vec<Row> = bulk::generate(&Faker, 500, 3);
+    assert_ne!(expected, default);
+    let rows: Vec<Row> = fake::par_generate(&Faker, 500, 3);
+    assert_eq!(rows, expected);
+
+    // `install` runs on a thread of the pool, which needs stable mode too
+    for threads in [1, 4] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let rows: Vec<Row> = pool.install(|| {
+            let _stable = fake::stable::enable();
+            fake::par_generate(&Faker, 500, 3)
+        });
+        assert_eq!(rows, expected);
+    }
+}
@@ -0,0 +1,86 @@
+use fake::combinator::{constant, from_fn, FakerExt};
+use fake::faker::name::en::Name;
+use fake::{Dummy, Fake, Faker, Rng};
+use rand::SeedableRng;
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn ranges_keep_iterator_methods() {
+    let doubled: Vec<u32> = (1..4).map(|x| x * 2).collect();
+    assert_eq!(doubled, [2, 4, 6]);
+    let odd: Vec<u32> = vec![1, 2, 3].into_iter().filter(|x| x % 2 == 1).collect();
+    assert_eq!(odd, [1, 3]);
+
+    let n: u32 = (1..4u32).fake_map(|x: u32| x * 2).fake();
+    assert!([2, 4, 6].contains(&n));
+}
+
+#[test]
+fn filter_accepts() {
+    for _ in 0..100 {
+        let n: u32 = (0..100u32).fake_filter(|n: &u32| n % 2 == 0).fake();
+        assert_eq!(n % 2, 0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "filter rejected 11 consecutive values")]
+fn filter_exhausts_retries() {
+    let _: u32 = (0..100u32)
+        .fake_filter(|_: &u32| false)
+        .retries(10)
+        .fake_with_rng(&mut rng());
+}
+
+#[test]
+fn and_then_uses_both_fakers() {
+    for _ in 0..100 {
+        let v: Vec<u8> = (1..5usize)
+            .fake_and_then(|n: usize| (Faker, n..n + 1))
+            .fake();
+        assert!((1..5).contains(&v.len()));
+    }
+}
+
+#[test]
+fn from_fn_receives_rng() {
+    let faker = from_fn(|rng| rng.random_range(10..20u32));
+    let a: u32 = faker.fake_with_rng(&mut rng());
+    let b: u32 = faker.fake_with_rng(&mut rng());
+    assert_eq!(a, b);
+    assert!((10..20).contains(&a));
+}
+
+#[test]
+fn constant_clones() {
+    let s: String = constant(String::from("fixed")).fake();
+    assert_eq!(s, "fixed");
+    let v: Vec<u8> = constant(vec![1u8, 2]).fake();
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn in_derive() {
+    #[derive(Debug, Dummy)]
+    struct User {
+        #[dummy(faker = "Name().fake_map(|s: String| s.to_uppercase())")]
+        name: String,
+        #[dummy(faker = "(0..1000u32).fake_filter(|n: &u32| *n >= 500)")]
+        score: u32,
+        #[dummy(faker = "from_fn(|rng| rng.random_range(18..=99))")]
+        age: u8,
+        #[dummy(faker = "constant(\"active\")")]
+        status: &'static str,
+    }
+
+    for _ in 0..50 {
+        let user: User = Faker.fake();
+        assert_eq!(user.name, user.name.to_uppercase());
+        assert!(user.score >= 500);
+        assert!((18..=99).contains(&user.age));
+        assert_eq!(user.status, "active");
+    }
+}
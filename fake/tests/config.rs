@@ -0,0 +1,76 @@
+use fake::{Dummy, Fake, Faker, FakerConfig};
+use std::collections::HashMap;
+
+#[derive(Debug, Dummy)]
+struct Tag {
+    labels: Vec<Option<String>>,
+}
+
+#[derive(Debug, Dummy)]
+struct Post {
+    title: String,
+    tags: Vec<Tag>,
+    meta: Option<HashMap<String, Vec<u8>>>,
+}
+
+fn check(post: &Post, none: bool) {
+    assert_eq!(post.title.len(), 3);
+    assert_eq!(post.tags.len(), 2);
+    for tag in &post.tags {
+        assert_eq!(tag.labels.len(), 2);
+        for label in &tag.labels {
+            assert_eq!(label.is_none(), none);
+            if let Some(label) = label {
+                assert_eq!(label.len(), 3);
+            }
+        }
+    }
+    assert_eq!(post.meta.is_none(), none);
+    for (key, value) in post.meta.iter().flatten() {
+        assert_eq!(key.len(), 3);
+        assert_eq!(value.len(), 2);
+    }
+}
+
+#[test]
+fn nested_in_derive() {
+    let config = FakerConfig::default()
+        .string_len(3..4)
+        .collection_len(2..=2);
+
+    for _ in 0..20 {
+        check(&config.none_ratio(0).fake(), false);
+        check(&config.none_ratio(100).fake(), true);
+    }
+
+    let _guard = config.none_ratio(100).scoped();
+    check(&Faker.fake(), true);
+}
+
+#[test]
+fn scope_ends_with_guard() {
+    {
+        let _guard = FakerConfig::default().collection_len(7..8).scoped();
+        assert_eq!(Faker.fake::<Vec<u8>>().len(), 7);
+    }
+    assert_eq!(FakerConfig::current(), FakerConfig::DEFAULT);
+}
+
+#[test]
+fn ranges() {
+    use std::ops::Bound;
+
+    let config = FakerConfig::default().string_len(..=usize::MAX);
+    assert_eq!(config.string_len_range(), 0..usize::MAX);
+    let config = FakerConfig::default().collection_len((Bound::Excluded(1), Bound::Included(3)));
+    assert_eq!(config.collection_len_range(), 2..4);
+}
+
+#[test]
+#[should_panic(expected = "length range must not be empty")]
+fn excluded_max_is_empty() {
+    use std::ops::Bound;
+
+    let _ = FakerConfig::default()
+        .string_len((Bound::Excluded(usize::MAX), Bound::Included(usize::MAX)));
+}
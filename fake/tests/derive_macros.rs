@@ -36,6 +36,7 @@ mod field_options {
             #[derive(Dummy, Debug, Eq, PartialEq)]
             enum MyEnum {}
 
+            #[allow(unreachable_code)]
             let _o: MyEnum = Faker.fake_with_rng(&mut rng());
         }
 
@@ -382,3 +383,90 @@ mod test_generic {
         assert_eq!(o.1, 0.56344515);
     }
 }
+
+mod stable_fields {
+    use super::*;
+    use fake::faker::name::en::Name;
+
+    #[derive(Debug, Dummy)]
+    #[dummy(stable_fields)]
+    struct Before {
+        id: u64,
+        #[dummy(faker = "Name()")]
+        name: String,
+        #[dummy(faker = "1..100")]
+        age: u8,
+    }
+
+    #[derive(Debug, Dummy)]
+    #[dummy(stable_fields)]
+    #[allow(dead_code)]
+    struct After {
+        #[dummy(faker = "1..100")]
+        age: u8,
+        email: Option<String>,
+        #[dummy(faker = "Name()")]
+        name: String,
+        #[dummy(default)]
+        notes: String,
+        id: u64,
+    }
+
+    #[test]
+    fn same_values_by_name() {
+        for seed in 0..20 {
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(seed);
+            let before: Before = Faker.fake_with_rng(&mut rng);
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(seed);
+            let after: After = Faker.fake_with_rng(&mut rng);
+
+            assert_eq!(before.id, after.id);
+            assert_eq!(before.name, after.name);
+            assert_eq!(before.age, after.age);
+            assert_eq!(after.notes, "");
+        }
+    }
+
+    #[test]
+    fn one_draw_from_parent() {
+        let mut rng1 = rng();
+        let mut rng2 = rng();
+        let _: Before = Faker.fake_with_rng(&mut rng1);
+        let _: u64 = rand::RngCore::next_u64(&mut rng2);
+        assert_eq!(
+            rand::RngCore::next_u64(&mut rng1),
+            rand::RngCore::next_u64(&mut rng2)
+        );
+    }
+
+    #[test]
+    fn tuple_and_enum() {
+        #[derive(Debug, Dummy, PartialEq)]
+        #[dummy(stable_fields)]
+        struct Pair(
+            #[dummy(faker = "0..1000")] u32,
+            #[dummy(faker = "0..1000")] u32,
+        );
+
+        #[derive(Debug, Dummy, PartialEq)]
+        #[dummy(stable_fields)]
+        enum Shape {
+            Point(
+                #[dummy(faker = "0..1000")] u32,
+                #[dummy(faker = "0..1000")] u32,
+            ),
+            Circle {
+                #[dummy(faker = "0..1000")]
+                radius: u32,
+            },
+        }
+
+        let pair: Pair = Faker.fake_with_rng(&mut rng());
+        assert_ne!(pair.0, pair.1);
+        let shape: Shape = Faker.fake_with_rng(&mut rng());
+        match shape {
+            Shape::Point(x, y) => assert_ne!(x, y),
+            Shape::Circle { radius } => assert!(radius < 1000),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use fake::distribution::{Exponential, LogNormal, Normal, Poisson, Zipf};
+use fake::Fake;
+use rand::SeedableRng;
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+fn mean<C>(config: &C) -> f64
+where
+    f64: fake::Dummy<C>,
+{
+    let mut rng = rng();
+    (0..10_000)
+        .map(|_| config.fake_with_rng::<f64, _>(&mut rng))
+        .sum::<f64>()
+        / 10_000.0
+}
+
+#[test]
+fn sample_means() {
+    assert!((mean(&Normal::new(10.0, 2.0)) - 10.0).abs() < 0.1);
+    assert!((mean(&Exponential::new(0.5)) - 2.0).abs() < 0.1);
+    assert!((mean(&Poisson::new(4.0)) - 4.0).abs() < 0.1);
+    assert!((mean(&LogNormal::new(0.0, 0.5)) - 0.125f64.exp()).abs() < 0.05);
+}
+
+#[test]
+fn clamped_to_bounds() {
+    let mut rng = rng();
+    let config = Normal::new(0.0, 100.0).min(-10.0).max(10.0);
+    for _ in 0..1000 {
+        let v: i8 = config.fake_with_rng(&mut rng);
+        assert!((-10..=10).contains(&v));
+        let v: f32 = config.fake_with_rng(&mut rng);
+        assert!((-10.0..=10.0).contains(&v));
+    }
+}
+
+#[test]
+fn integers_saturate() {
+    let mut rng = rng();
+    let v: u8 = Normal::new(-1e9, 1.0).fake_with_rng(&mut rng);
+    assert_eq!(v, 0);
+    let v: u8 = Normal::new(1e9, 1.0).fake_with_rng(&mut rng);
+    assert_eq!(v, 255);
+}
+
+#[test]
+fn zipf_ranks() {
+    let mut rng = rng();
+    let mut counts = [0usize; 11];
+    for _ in 0..10_000 {
+        let rank: usize = Zipf::new(10, 1.5).fake_with_rng(&mut rng);
+        counts[rank] += 1;
+    }
+    assert_eq!(counts[0], 0);
+    assert!(counts[1..].windows(2).all(|w| w[0] > w[1]));
+}
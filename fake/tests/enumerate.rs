@@ -0,0 +1,138 @@
+use fake::faker::address::raw::*;
+use fake::faker::boolean::raw::*;
+use fake::faker::currency::raw::*;
+use fake::faker::filesystem::raw::*;
+use fake::faker::name::raw::*;
+use fake::locales::*;
+use fake::{Dummy, Enumerate, Fake, Faker};
+use std::collections::HashSet;
+
+/// Every sampled value is enumerated, and the `String` impl lists the same
+/// values as the `&str` one.
+fn check<C>(faker: C)
+where
+    for<'a> &'a str: Dummy<C>,
+    &'static str: Enumerate<C>,
+    String: Enumerate<C>,
+{
+    let all: Vec<&str> = faker.fake_all::<&str>().collect();
+    let strings: Vec<String> = faker.fake_all::<String>().collect();
+    assert!(!all.is_empty());
+    assert_eq!(all, strings);
+
+    let all: HashSet<&str> = all.into_iter().collect();
+    for _ in 0..200 {
+        let value: &str = faker.fake();
+        assert!(all.contains(value), "{:?} not enumerated", value);
+    }
+}
+
+#[test]
+fn locale_tables() {
+    check(StateAbbr(EN));
+    check(StateAbbr(FR_FR));
+    check(CountryCode(ZH_CN));
+    check(TimeZone(PT_BR));
+    check(CurrencyCode(EN));
+    check(CurrencySymbol(DE_DE));
+    check(FirstName(JA_JP));
+    check(LastName(IT_IT));
+    check(FileExtension(EN));
+
+    assert_eq!(
+        StateAbbr(EN).fake_all::<&str>().count(),
+        EN::ADDRESS_STATE_ABBR.len()
+    );
+}
+
+#[test]
+fn runtime_locale() {
+    for locale in [Locale::En, Locale::FrFr, Locale::DeCh] {
+        check(CurrencyName(locale));
+    }
+    assert!(StateName(Locale::En)
+        .fake_all::<&str>()
+        .eq(StateName(EN).fake_all::<&str>()));
+}
+
+#[test]
+fn mime_types() {
+    let all: HashSet<String> = MimeType(EN).fake_all::<String>().collect();
+    assert!(all.contains("application/json"));
+    for _ in 0..200 {
+        assert!(all.contains(&MimeType(EN).fake::<String>()));
+    }
+}
+
+#[test]
+fn booleans() {
+    assert_eq!(Faker.fake_all::<bool>().collect::<Vec<_>>(), [false, true]);
+    assert_eq!(
+        Boolean(EN, 50).fake_all::<bool>().collect::<Vec<_>>(),
+        [false, true]
+    );
+    assert_eq!(
+        Boolean(EN, 0).fake_all::<bool>().collect::<Vec<_>>(),
+        [false]
+    );
+    assert_eq!(
+        Boolean(EN, 100).fake_all::<bool>().collect::<Vec<_>>(),
+        [true]
+    );
+}
+
+#[test]
+fn derived_enum() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Dummy, Enumerate)]
+    enum Status {
+        Active,
+        Suspended,
+        #[dummy(skip)]
+        Deleted,
+        Pending,
+    }
+
+    let all: Vec<Status> = Faker.fake_all::<Status>().collect();
+    assert_eq!(all, [Status::Active, Status::Suspended, Status::Pending]);
+    assert!(!all.contains(&Status::Deleted));
+    for _ in 0..100 {
+        assert!(all.contains(&Faker.fake::<Status>()));
+    }
+
+    #[derive(Debug, PartialEq, Dummy, Enumerate)]
+    enum Account {
+        Guest,
+        #[dummy(skip)]
+        Banned {
+            reason: String,
+        },
+        #[dummy(skip)]
+        Merged(u64),
+        Member,
+    }
+
+    let all: Vec<Account> = Faker.fake_all::<Account>().collect();
+    assert_eq!(all, [Account::Guest, Account::Member]);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn http_status_codes() {
+    use fake::faker::http::raw::*;
+
+    let rfc: Vec<http::StatusCode> = RfcStatusCode(EN).fake_all::<http::StatusCode>().collect();
+    assert!(rfc.contains(&http::StatusCode::IM_A_TEAPOT));
+    assert_eq!(
+        RfcStatusCode(EN).fake_all::<String>().next().unwrap(),
+        "100 Continue"
+    );
+    assert_eq!(
+        ValidStatusCode(EN).fake_all::<http::StatusCode>().count(),
+        500
+    );
+    let methods: HashSet<http::Method> = Faker.fake_all::<http::Method>().collect();
+    assert_eq!(methods.len(), 9);
+    for _ in 0..100 {
+        assert!(methods.contains(&Faker.fake::<http::Method>()));
+    }
+}
@@ -0,0 +1,325 @@
+use fake::faker::lorem::raw::Words;
+use fake::faker::name::raw::{FirstName, Name};
+use fake::faker::number::raw::NumberWithFormat;
+use fake::locales::{Locale, ParseLocaleError, FR_FR, JA_JP};
+use fake::Fake;
+use rand::SeedableRng;
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn parse() {
+    assert_eq!("fr_fr".parse(), Ok(Locale::FrFr));
+    assert_eq!("fr-FR".parse(), Ok(Locale::FrFr));
+    assert_eq!("EN".parse(), Ok(Locale::En));
+    assert_eq!(
+        "xx_xx".parse::<Locale>(),
+        Err(ParseLocaleError("xx_xx".to_string()))
+    );
+    for locale in Locale::ALL {
+        assert_eq!(locale.to_string().parse(), Ok(*locale));
+    }
+}
+
+#[test]
+fn same_values_as_the_static_locales() {
+    let name: String = Name(Locale::FrFr).fake_with_rng(&mut rng());
+    assert_eq!(name, Name(FR_FR).fake_with_rng::<String, _>(&mut rng()));
+
+    let first: &str = FirstName(Locale::JaJp).fake_with_rng(&mut rng());
+    assert_eq!(first, FirstName(JA_JP).fake_with_rng::<&str, _>(&mut rng()));
+
+    let words: Vec<String> = Words(Locale::FrFr, 2..5).fake_with_rng(&mut rng());
+    assert_eq!(
+        words,
+        Words(FR_FR, 2..5).fake_with_rng::<Vec<String>, _>(&mut rng())
+    );
+
+    let number: String = NumberWithFormat(Locale::En, "##-##").fake_with_rng(&mut rng());
+    assert_eq!(number.len(), 5);
+}
+
+#[test]
+fn wrapped_outputs() {
+    let locale: Locale = "ja_jp".parse().unwrap();
+    let names: Option<String> = Name(locale).fake_with_rng(&mut rng());
+    assert_eq!(
+        names,
+        Name(JA_JP).fake_with_rng::<Option<String>, _>(&mut rng())
+    );
+}
+
+#[test]
+fn inherited_tables() {
+    use fake::faker::job::raw::Field;
+    use fake::locales::{own_tables, Data, DE_CH, DE_DE};
+
+    let own = own_tables::<DE_CH>();
+    assert!(own.contains(&"ADDRESS_STATE"));
+    assert!(!own.contains(&"JOB_FIELD"));
+    assert_eq!(DE_CH::JOB_FIELD, DE_DE::JOB_FIELD);
+    assert_eq!(
+        Field(DE_CH).fake_with_rng::<String, _>(&mut rng()),
+        Field(DE_DE).fake_with_rng::<String, _>(&mut rng())
+    );
+
+    assert_eq!(Locale::DeCh.parent(), Some(Locale::DeDe));
+    assert_eq!(Locale::DeLi.parent(), Some(Locale::DeDe));
+    assert_eq!(Locale::DeDe.parent(), Some(Locale::En));
+    assert_eq!(Locale::FrFr.parent(), Some(Locale::En));
+    assert_eq!(DE_CH::PARENT, Some(Locale::DeDe));
+    assert_eq!(Locale::En.parent(), None);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn file_locale() {
+    use fake::faker::address::raw::CityName;
+    use fake::faker::internet::raw::Password;
+    use fake::locales::{FileLocale, DE_DE};
+
+    let locale = FileLocale::from_toml(
+        r#"
+        # in-house word lists
+        fallback = "de-DE"
+        NAME_FIRST_NAME = ["Ada", 'Grace'] # trailing comment
+        ADDRESS_CITY_SUFFIX = [
+            "ville",
+            "ée",
+        ]
+        INTERNET_PASSWORD_CHARS = ["x"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(locale.fallback(), Locale::DeDe);
+    assert_eq!(
+        locale.overridden_tables(),
+        [
+            "NAME_FIRST_NAME",
+            "ADDRESS_CITY_SUFFIX",
+            "INTERNET_PASSWORD_CHARS"
+        ]
+    );
+
+    for _ in 0..10 {
+        let first: &str = FirstName(locale).fake();
+        assert!(["Ada", "Grace"].contains(&first));
+        let city: String = CityName(locale).fake();
+        assert!(city.ends_with("ville") || city.ends_with("ée"));
+    }
+    assert_eq!(Password(locale, 3..4).fake::<String>(), "xxx");
+    assert_eq!(
+        Words(locale, 2..5).fake_with_rng::<Vec<String>, _>(&mut rng()),
+        Words(DE_DE, 2..5).fake_with_rng::<Vec<String>, _>(&mut rng())
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn file_locale_city_names_from_fallback() {
+    use fake::faker::address::raw::CityName;
+    use fake::locales::FileLocale;
+
+    for fallback in [Locale::DeDe, Locale::DeCh, Locale::DeLi] {
+        let locale = FileLocale::from_toml(&format!(
+            "fallback = \"{}\"\nINTERNET_PASSWORD_CHARS = [\"x\"]",
+            fallback.code()
+        ))
+        .unwrap();
+        for seed in 0..20 {
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(seed);
+            let ours: String = CityName(locale).fake_with_rng(&mut rng);
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(seed);
+            let theirs: String = CityName(fallback).fake_with_rng(&mut rng);
+            assert_eq!(ours, theirs);
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn file_locale_errors() {
+    use fake::locales::{FileLocale, FileLocaleError};
+
+    let err = |src| FileLocale::from_toml(src).unwrap_err();
+    assert_eq!(
+        err("NAME_FIRST_NAME = [\"Ada\"]\nNAME_TITLES = [\"Dr.\"]"),
+        FileLocaleError::UnknownTable("NAME_TITLES".into())
+    );
+    assert!(matches!(
+        err("NAME_FIRST_NAME = [\"Ada\"]\nNAME_FIRST_NAME = [\"Grace\"]"),
+        FileLocaleError::Syntax { message, .. } if message.contains("duplicate key")
+    ));
+    assert_eq!(
+        err("NAME_FIRST_NAME = []"),
+        FileLocaleError::InvalidTable {
+            table: "NAME_FIRST_NAME".into(),
+            expected: "a non-empty array of strings"
+        }
+    );
+    assert_eq!(
+        err("fallback = \"xx\""),
+        FileLocaleError::Fallback(ParseLocaleError("xx".into()))
+    );
+    assert!(matches!(
+        err("\nNAME_FIRST_NAME = [\"Ada\""),
+        FileLocaleError::Syntax { line: 2, .. }
+    ));
+    assert_eq!(
+        err("[names]"),
+        FileLocaleError::UnknownTable("names".into())
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn file_locale_from_json() {
+    use fake::locales::{FileLocale, FileLocaleError};
+
+    let locale =
+        FileLocale::from_json(r#"{"fallback": "fr_fr", "NAME_LAST_NAME": ["Lovelace"]}"#).unwrap();
+    assert_eq!(
+        Name(locale).fake::<String>().split(' ').last(),
+        Some("Lovelace")
+    );
+    assert_eq!(
+        FileLocale::from_json(r#"{"NAME_TPL": ["{FirstName}"]}"#).unwrap_err(),
+        FileLocaleError::InvalidTable {
+            table: "NAME_TPL".into(),
+            expected: "a string"
+        }
+    );
+}
+
+#[test]
+fn builtin_locales_are_valid() {
+    use fake::locales::*;
+
+    macro_rules! validate_all {
+        ($($locale:ident),+) => {
+            $(assert_eq!(validate(&$locale), Ok(()), stringify!($locale));)+
+        };
+    }
+
+    validate_all!(
+        EN, FR_FR, ZH_TW, ZH_CN, JA_JP, AR_SA, PT_BR, PT_PT, DE_DE, DE_AT, DE_CH, DE_LI, IT_IT
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn validate_reports_issues() {
+    use fake::locales::{validate, FileLocale, Issue};
+
+    let locale = FileLocale::from_toml(
+        r#"
+        NAME_FIRST_NAME = ["Ada", "Grace", "Ada"]
+        NAME_TPL = "{FirstName} {MiddleName} {LastName}"
+        PHONE_NUMBER_FORMATS = ["^##-####", "{AreaCode}-####", "555"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&locale),
+        Err(vec![
+            Issue::Duplicate {
+                table: "NAME_FIRST_NAME",
+                entry: "Ada".into()
+            },
+            Issue::UnknownPlaceholder {
+                table: "NAME_TPL",
+                placeholder: "MiddleName".into()
+            },
+            Issue::MalformedFormat {
+                table: "PHONE_NUMBER_FORMATS",
+                format: "{AreaCode}-####"
+            },
+            Issue::MalformedFormat {
+                table: "PHONE_NUMBER_FORMATS",
+                format: "555"
+            },
+        ])
+    );
+}
+
+#[test]
+fn mixed() {
+    use fake::faker::name::raw::LastName;
+    use fake::locales::{Data, Mixed, EN};
+
+    let ja_only = Mixed(&[(Locale::En, 0), (Locale::JaJp, 1)]);
+    let first: &str = FirstName(ja_only).fake();
+    assert!(JA_JP::NAME_FIRST_NAME.contains(&first));
+
+    let mixed = Mixed(&[(Locale::En, 1), (Locale::JaJp, 1)]);
+    let mut locales = Vec::new();
+    for _ in 0..50 {
+        let last: &str = LastName(mixed).fake();
+        locales.push(EN::NAME_LAST_NAME.contains(&last));
+    }
+    assert!(locales.contains(&true) && locales.contains(&false));
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn mixed_per_record() {
+    use fake::faker::name::raw::LastName;
+    use fake::locales::{Data, Mixed, EN};
+    use fake::{Dummy, Faker};
+
+    const MIXED: Mixed = Mixed(&[(Locale::En, 1), (Locale::JaJp, 1)]);
+
+    #[derive(Dummy)]
+    struct User {
+        #[dummy(faker = "FirstName(MIXED)")]
+        first: &'static str,
+        #[dummy(faker = "LastName(MIXED)")]
+        last: &'static str,
+        // equal weights written elsewhere share the pick
+        #[dummy(faker = "LastName(Mixed(&[(Locale::En, 1), (Locale::JaJp, 1)]))")]
+        maiden: &'static str,
+    }
+
+    fn is_en(user: &User) -> bool {
+        let en = EN::NAME_FIRST_NAME.contains(&user.first);
+        assert_eq!(en, EN::NAME_LAST_NAME.contains(&user.last));
+        assert_eq!(en, EN::NAME_LAST_NAME.contains(&user.maiden));
+        en
+    }
+
+    for _ in 0..50 {
+        is_en(&Faker.fake());
+    }
+
+    // each element of a collection is a record of its own
+    #[derive(Dummy)]
+    struct Team {
+        #[dummy(faker = "LastName(MIXED)")]
+        name: &'static str,
+        #[dummy(faker = "(Faker, 20..21)")]
+        members: Vec<User>,
+        leads: [User; 20],
+    }
+
+    let team: Team = Faker.fake();
+    assert!(!team.name.is_empty());
+    let en: Vec<bool> = team.members.iter().chain(&team.leads).map(is_en).collect();
+    assert!(en.contains(&true) && en.contains(&false));
+
+    #[derive(Dummy)]
+    #[dummy(mixed_per_field)]
+    struct PerField {
+        #[dummy(faker = "FirstName(MIXED)")]
+        first: &'static str,
+        #[dummy(faker = "LastName(MIXED)")]
+        last: &'static str,
+    }
+
+    let mixed_up = (0..50).any(|_| {
+        let p: PerField = Faker.fake();
+        EN::NAME_FIRST_NAME.contains(&p.first) != EN::NAME_LAST_NAME.contains(&p.last)
+    });
+    assert!(mixed_up);
+}
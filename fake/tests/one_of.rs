@@ -0,0 +1,81 @@
+use fake::faker::name::en::{FirstName, LastName};
+use fake::utils::{Choice, OneOf, WrappedVal};
+use fake::{one_of, Fake};
+
+#[test]
+fn one_of_uniform() {
+    let faker = OneOf::new((0..10u8, 100..110u8, 200..210u8));
+    let mut seen = [false; 3];
+    for _ in 0..200 {
+        let n: u8 = faker.fake();
+        seen[usize::from(n / 100)] = true;
+        assert!(n % 100 < 10);
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+#[test]
+fn one_of_weights() {
+    let faker = OneOf::new((0..10u8, 100..110u8)).weights([0u64, 1]);
+    for _ in 0..100 {
+        assert!(faker.fake::<u8>() >= 100);
+    }
+}
+
+#[test]
+#[should_panic(expected = "got 3 weights for 2 choices")]
+fn one_of_weight_count() {
+    let _ = OneOf::new((0..10u8, 100..110u8)).weights([1, 2, 3]);
+}
+
+#[test]
+fn one_of_wrapped() {
+    #[derive(Debug, PartialEq)]
+    struct Id(u32);
+
+    impl fake::Dummy<std::ops::Range<u32>> for Id {
+        fn dummy_with_rng<R: fake::Rng + ?Sized>(r: &std::ops::Range<u32>, rng: &mut R) -> Self {
+            Id(r.clone().fake_with_rng(rng))
+        }
+    }
+
+    let faker = OneOf::new((0..5u32, 10..15u32));
+    for _ in 0..50 {
+        let id: WrappedVal<Id> = faker.fake();
+        let Id(n) = id.0;
+        assert!(n < 5 || (10..15).contains(&n));
+    }
+}
+
+#[test]
+fn choice() {
+    let items = ["red", "green", "blue"];
+    for _ in 0..50 {
+        let color: &str = Choice::new(&items).fake();
+        assert!(items.contains(&color));
+    }
+
+    let blue: String = Choice::new(&items).weights([0, 0, 5]).fake();
+    assert_eq!(blue, "blue");
+    let qty: u8 = Choice::new(&[1, 5, 10]).weights([0.0f32, 1.0, 0.0]).fake();
+    assert_eq!(qty, 5);
+}
+
+#[test]
+#[should_panic(expected = "got 2 weights for 3 choices")]
+fn choice_weight_count() {
+    let _ = Choice::new(&["S", "M", "L"]).weights([1, 2]);
+}
+
+#[test]
+fn one_of_macro() {
+    let name: String = one_of![FirstName(), LastName()].fake();
+    assert!(!name.is_empty());
+
+    for _ in 0..50 {
+        let n: u8 = one_of![0u64 => 0..10, 1usize => 200..=255].fake();
+        assert!(n >= 200);
+        let n: u8 = one_of![1 => 0..10, 0.0 => 200..=255].fake();
+        assert!(n < 10);
+    }
+}
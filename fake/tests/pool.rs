@@ -0,0 +1,91 @@
+use std::cell::Cell;
+use std::collections::HashSet;
+
+use fake::combinator::from_fn;
+use fake::faker::company::en::CompanyName;
+use fake::pool::Pool;
+use fake::utils::WrappedVal;
+use fake::{Dummy, Fake, Faker};
+use rand::SeedableRng;
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+fn counts(pool: &Pool<u32>) -> Vec<usize> {
+    let mut rng = rng();
+    let mut counts = vec![0; pool.values().len()];
+    for _ in 0..10_000 {
+        let v: u32 = pool.fake_with_rng(&mut rng);
+        counts[v as usize] += 1;
+    }
+    counts
+}
+
+#[test]
+fn cardinality() {
+    let mut rng = rng();
+    let pool = Pool::new_with_rng(CompanyName(), 20, &mut rng);
+    let names: HashSet<String> = (0..1000)
+        .map(|_| pool.fake_with_rng::<String, _>(&mut rng))
+        .collect();
+    assert!(names.len() <= 20);
+    assert!(names.iter().all(|n| pool.values().contains(n)));
+
+    let name: &str = Pool::from_values(vec!["a", "b"]).fake_with_rng(&mut rng);
+    assert!(name == "a" || name == "b");
+}
+
+#[test]
+fn faker_runs_n_times() {
+    let calls = Cell::new(0);
+    let faker = from_fn(|_| {
+        calls.set(calls.get() + 1);
+        calls.get()
+    });
+    let pool: Pool<u32> = Pool::new(faker, 5);
+    for _ in 0..100 {
+        let _: u32 = pool.fake();
+    }
+    assert_eq!(calls.get(), 5);
+    assert_eq!(pool.values(), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn sampling() {
+    let values: Vec<u32> = (0..5).collect();
+
+    let uniform = counts(&Pool::from_values(values.clone()));
+    assert!(uniform.iter().all(|&c| (1800..2200).contains(&c)));
+
+    let weighted = counts(&Pool::from_values(values.clone()).weights([0, 1, 0, 3, 0]));
+    assert_eq!(weighted[0] + weighted[2] + weighted[4], 0);
+    assert!((2300..2700).contains(&weighted[1]));
+
+    let zipf = counts(&Pool::from_values(values).zipf(1.5));
+    assert!(zipf.windows(2).all(|w| w[0] > w[1]));
+}
+
+#[test]
+#[should_panic(expected = "expected one weight per value")]
+fn weights_length() {
+    Pool::from_values(vec![1, 2, 3]).weights([1, 2]);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Tag(&'static str);
+
+#[derive(Debug, Dummy)]
+struct Post {
+    #[dummy(
+        faker = "Pool::from_values(vec![Tag(\"rust\"), Tag(\"go\")])",
+        wrapper = "WrappedVal"
+    )]
+    tag: Tag,
+}
+
+#[test]
+fn wrapped_values() {
+    let post: Post = Faker.fake();
+    assert!(post.tag == Tag("rust") || post.tag == Tag("go"));
+}
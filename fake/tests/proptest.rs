@@ -0,0 +1,48 @@
+#![cfg(feature = "proptest")]
+
+use fake::faker::address::en::CityName;
+use fake::faker::name::en::Name;
+use fake::Dummy;
+use proptest::prelude::*;
+
+#[derive(Debug, Clone, Dummy)]
+struct Customer {
+    #[dummy(faker = "Name()")]
+    name: String,
+    #[dummy(faker = "CityName()")]
+    city: String,
+    #[dummy(faker = "18..100")]
+    age: u8,
+}
+
+proptest! {
+    #[test]
+    fn fake_strategy(name in fake::proptest::strategy::<String, _>(Name()), n in 0..10u8) {
+        prop_assert!(!name.is_empty());
+        prop_assert!(n < 10);
+    }
+
+    #[test]
+    fn derived_strategy(customer in fake::proptest::any_fake::<Customer>()) {
+        prop_assert!(!customer.city.is_empty());
+        prop_assert!((18..100).contains(&customer.age));
+    }
+}
+
+#[test]
+fn runner_seed_controls_values() {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    let strategy = fake::proptest::any_fake::<Customer>();
+    let a = strategy
+        .new_tree(&mut TestRunner::deterministic())
+        .unwrap()
+        .current();
+    let b = strategy
+        .new_tree(&mut TestRunner::deterministic())
+        .unwrap()
+        .current();
+    assert_eq!(a.name, b.name);
+    assert_eq!(a.city, b.city);
+}
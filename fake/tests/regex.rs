@@ -0,0 +1,43 @@
+#![cfg(feature = "regex")]
+
+use fake::{Fake, Regex, RegexFaker};
+use rand::SeedableRng;
+
+#[test]
+fn classes_and_quantifiers() {
+    let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(1);
+    for _ in 0..100 {
+        let s: String = Regex("[A-Z]{2}-\\d{4}").fake_with_rng(&mut rng);
+        let bytes = s.as_bytes();
+        assert_eq!(bytes.len(), 7, "{}", s);
+        assert!(bytes[..2].iter().all(u8::is_ascii_uppercase), "{}", s);
+        assert_eq!(bytes[2], b'-');
+        assert!(bytes[3..].iter().all(u8::is_ascii_digit), "{}", s);
+    }
+}
+
+#[test]
+fn alternation_groups_and_caps() {
+    let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(2);
+    let f = RegexFaker::new("^(cat|dog)s?:\\w+$").unwrap().max_repeat(4);
+    for _ in 0..100 {
+        let s: String = f.fake_with_rng(&mut rng);
+        let (head, tail) = s.split_once(':').unwrap();
+        assert!(["cat", "cats", "dog", "dogs"].contains(&head), "{}", s);
+        assert!((1..=5).contains(&tail.len()), "{}", s);
+        assert!(tail.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    }
+}
+
+#[test]
+fn non_ascii_classes() {
+    let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(3);
+    let s: String = Regex("[а-я]{5}").fake_with_rng(&mut rng);
+    assert!(s.chars().all(|c| ('а'..='я').contains(&c)));
+    assert_eq!(s.chars().count(), 5);
+}
+
+#[test]
+fn invalid_pattern() {
+    assert!(RegexFaker::new("[a-").is_err());
+}
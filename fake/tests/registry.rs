@@ -0,0 +1,74 @@
+use fake::faker::internet::raw::Password;
+use fake::faker::job::raw::Title;
+use fake::locales::{Locale, PT_BR};
+use fake::registry::{self, Output, ParamKind, RegistryError, Value};
+use fake::Fake;
+use rand::SeedableRng;
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn lookup() {
+    let title = registry::find("job.Title").unwrap();
+    assert_eq!((title.category(), title.name()), ("job", "Title"));
+    assert_eq!(registry::find("Title").unwrap().category(), "name");
+    assert!(registry::get("lorem", "Words").is_some());
+    assert!(registry::find("Nope").is_none());
+
+    let password = registry::find("Password").unwrap().param().unwrap();
+    assert_eq!(password.kind, ParamKind::Range);
+    assert_eq!(password.default, "10..20");
+    assert_eq!(registry::find("Boolean").unwrap().output(), Output::Bool);
+    assert!(registry::fakers().count() > 70);
+}
+
+#[test]
+fn same_values_as_the_fakers() {
+    let faker = registry::build("job.Title", Locale::PtBr, None).unwrap();
+    let value: String = faker.fake_with_rng(&mut rng());
+    assert_eq!(value, Title(PT_BR).fake_with_rng::<String, _>(&mut rng()));
+
+    let faker = registry::build("Password", Locale::PtBr, Some("8..=8")).unwrap();
+    let value: String = faker.fake_with_rng(&mut rng());
+    assert_eq!(
+        value,
+        Password(PT_BR, 8..9).fake_with_rng::<String, _>(&mut rng())
+    );
+    assert_eq!(value.len(), 8);
+}
+
+#[test]
+fn typed_values() {
+    let mut rng = rng();
+    let words = registry::build("Words", Locale::En, Some("3")).unwrap();
+    match words.fake_with_rng(&mut rng) {
+        Value::List(words) => assert_eq!(words.len(), 3),
+        other => panic!("unexpected {:?}", other),
+    }
+    let text: String = words.fake_with_rng(&mut rng);
+    assert_eq!(text.split(' ').count(), 3);
+
+    let latitude = registry::build("Latitude", Locale::En, None).unwrap();
+    match latitude.fake_with_rng(&mut rng) {
+        Value::Float(f) => assert!((-90.0..=90.0).contains(&f)),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn build_errors() {
+    let err = |key, arg| registry::build(key, Locale::En, arg).err().unwrap();
+    assert_eq!(
+        err("Nope", None),
+        RegistryError::UnknownFaker("Nope".to_string())
+    );
+    assert_eq!(
+        err("Geohash", Some("x")),
+        RegistryError::InvalidArgument {
+            faker: "Geohash".to_string(),
+            arg: "x".to_string()
+        }
+    );
+}
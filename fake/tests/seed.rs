@@ -0,0 +1,98 @@
+use fake::faker::name::en::Name;
+use fake::{Fake, Faker};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[test]
+fn set_seed_is_reproducible() {
+    fake::set_seed(7);
+    let a: (u64, String, Vec<u8>) = (Faker, Name(), Faker).fake();
+    fake::set_seed(7);
+    let b: (u64, String, Vec<u8>) = (Faker, Name(), Faker).fake();
+    assert_eq!(a, b);
+    assert_eq!(fake::current_seed(), Some(7));
+    fake::clear_seed();
+    assert_eq!(fake::current_seed(), None);
+}
+
+#[test]
+fn seeded_guard_restores_previous_state() {
+    fake::set_seed(1);
+    let expected: u64 = {
+        let _guard = fake::seeded(2);
+        Faker.fake()
+    };
+    assert_eq!(fake::current_seed(), Some(1));
+    let first: u64 = Faker.fake();
+
+    fake::set_seed(1);
+    {
+        let guard = fake::seeded(2);
+        assert_eq!(guard.seed(), 2);
+        assert_eq!(Faker.fake::<u64>(), expected);
+    }
+    assert_eq!(Faker.fake::<u64>(), first);
+    fake::clear_seed();
+}
+
+#[test]
+fn seed_is_thread_local() {
+    fake::set_seed(3);
+    let here: u64 = Faker.fake();
+    let there = std::thread::spawn(|| {
+        fake::set_seed(3);
+        Faker.fake::<u64>()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(here, there);
+    fake::clear_seed();
+}
+
+#[test]
+fn fake_iter_uses_seed() {
+    fake::set_seed(11);
+    let a: Vec<u16> = Faker.fake_iter().take(8).collect();
+    fake::set_seed(11);
+    let b: Vec<u16> = (0..8).map(|_| Faker.fake()).collect();
+    assert_eq!(a, b);
+    fake::clear_seed();
+}
+
+#[test]
+fn run_seeded_reseeds_each_run() {
+    let mut seeds = Vec::new();
+    fake::seed::run_seeded(5, |rng| {
+        let seed = fake::current_seed().unwrap();
+        let expected: u64 = Faker.fake_with_rng(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(Faker.fake_with_rng::<u64, _>(rng), expected);
+        seeds.push(seed);
+    });
+    assert_eq!(seeds.len(), 5);
+    assert_eq!(fake::current_seed(), None);
+}
+
+#[fake::test(runs = 10)]
+fn attribute_with_rng(rng: &mut impl Rng) {
+    let name: String = Name().fake_with_rng(rng);
+    assert!(name.contains(' '));
+}
+
+#[fake::test]
+fn attribute_without_rng() {
+    assert!(fake::current_seed().is_some());
+}
+
+#[fake::test]
+fn attribute_returning_result() -> Result<(), std::num::ParseIntError> {
+    let n: u8 = Faker.fake();
+    assert_eq!(n.to_string().parse::<u8>()?, n);
+    Ok(())
+}
+
+#[fake::test(runs = 3)]
+#[should_panic(expected = "boom")]
+fn attribute_panics(rng: &mut fake::seed::FakeRng) {
+    let _: u64 = Faker.fake_with_rng(rng);
+    panic!("boom");
+}
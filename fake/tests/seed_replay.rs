@@ -0,0 +1,9 @@
+//! Alone in its binary, as it sets `FAKE_SEED` for the whole process.
+
+#[test]
+fn run_seeded_replays_env_seed() {
+    std::env::set_var(fake::seed::FAKE_SEED_ENV, "1234");
+    let mut seeds = Vec::new();
+    fake::seed::run_seeded(10, |_| seeds.push(fake::current_seed().unwrap()));
+    assert_eq!(seeds, [1234]);
+}
@@ -0,0 +1,89 @@
+use fake::{Fake, Sequence};
+
+#[test]
+fn counts_by_step() {
+    let ids = Sequence::with_step(10, 5);
+    let values: Vec<u32> = (0..3).map(|_| ids.fake()).collect();
+    assert_eq!(values, [10, 15, 20]);
+    assert_eq!(ids.peek(), 25);
+
+    ids.reset();
+    assert_eq!(ids.fake::<i64>(), 10);
+}
+
+#[test]
+fn formats() {
+    let check = |format, expected: &[&str]| {
+        let seq = Sequence::with_format(7, 1, format);
+        let values: Vec<String> = (0..expected.len()).map(|_| seq.fake()).collect();
+        assert_eq!(values, expected, "{}", format);
+    };
+    check("ORD-{:04}", &["ORD-0007", "ORD-0008"]);
+    check("{:3}|", &["  7|", "  8|"]);
+    check("user{}@example.com", &["user7@example.com"]);
+    check("id-", &["id-7"]);
+
+    assert_eq!(Sequence::new(42).fake::<String>(), "42");
+}
+
+#[test]
+#[should_panic(expected = "unclosed")]
+fn unclosed_format() {
+    let _ = Sequence::with_format(0, 1, "ORD-{:04");
+}
+
+#[test]
+#[should_panic(expected = "placeholder must be")]
+fn invalid_placeholder() {
+    let _ = Sequence::with_format(0, 1, "ORD-{x}");
+}
+
+#[test]
+#[should_panic(expected = "width must be a number")]
+fn invalid_width() {
+    let _ = Sequence::with_format(0, 1, "ORD-{:0x}");
+}
+
+#[test]
+#[should_panic(expected = "sequence overflowed")]
+fn overflow_panics() {
+    let ids = Sequence::with_step(u64::MAX - 1, 1);
+    assert_eq!(ids.fake::<u64>(), u64::MAX - 1);
+    assert_eq!(ids.fake::<u64>(), u64::MAX);
+    let _: u64 = ids.fake();
+}
+
+#[test]
+fn last_values() {
+    let ids = Sequence::new(u64::MAX);
+    assert_eq!(ids.fake::<u64>(), u64::MAX);
+    ids.reset();
+    assert_eq!(ids.fake::<u64>(), u64::MAX);
+
+    let ids = Sequence::with_step(u64::MAX - 3, 5);
+    assert_eq!(ids.fake::<u64>(), u64::MAX - 3);
+    let result = std::panic::catch_unwind(|| ids.fake::<u64>());
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "sequence value out of range")]
+fn value_out_of_range() {
+    let ids = Sequence::new(256);
+    let _: u8 = ids.fake();
+}
+
+#[test]
+fn shared_across_threads() {
+    static IDS: Sequence = Sequence::new(0);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(|| (0..100).map(|_| IDS.fake()).collect::<Vec<u64>>()))
+        .collect();
+    let mut all: Vec<u64> = handles
+        .into_iter()
+        .flat_map(|h| h.join().unwrap())
+        .collect();
+    all.sort_unstable();
+    assert_eq!(all, (0..400).collect::<Vec<_>>());
+}
@@ -0,0 +1,346 @@
+//! Golden tests of stable mode: the values below are part of the public API
+//! and may only change in a major release.
+//!
+//! After an intended change, regenerate the files of `tests/golden` with
+//! `FAKE_UPDATE_GOLDEN=1 cargo test --all-features --test stable`.
+
+use fake::locales::*;
+use fake::stable::{self, StableRng};
+use fake::{Fake, Faker};
+use rand::{RngCore, SeedableRng};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+const SEEDS: u64 = 4;
+
+fn rng(seed: u64) -> StableRng {
+    StableRng::seed_from_u64(seed)
+}
+
+/// Append `label: value` for each seed, generating under stable mode.
+macro_rules! golden {
+    ($out:ident; $($label:expr => $ty:ty = $faker:expr;)+) => {
+        $(
+            for seed in 0..SEEDS {
+                let _stable = stable::enable();
+                let value: $ty = $faker.fake_with_rng(&mut rng(seed));
+                writeln!($out, "{} #{}: {:?}", $label, seed, value).unwrap();
+            }
+        )+
+    };
+}
+
+/// Compare `actual` with `tests/golden/<name>.txt`, or overwrite the file
+/// when `FAKE_UPDATE_GOLDEN` is set.
+fn check(name: &str, actual: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "golden", name]
+        .iter()
+        .collect::<PathBuf>()
+        .with_extension("txt");
+    if std::env::var_os("FAKE_UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    for (expected, actual) in expected.lines().zip(actual.lines()) {
+        assert_eq!(
+            expected,
+            actual,
+            "stable output changed, see {}",
+            path.display()
+        );
+    }
+    assert_eq!(
+        expected.lines().count(),
+        actual.lines().count(),
+        "stable output changed, see {}",
+        path.display()
+    );
+}
+
+#[test]
+fn rng_stream() {
+    let mut out = String::new();
+    for seed in [0, 1, 42, u64::MAX] {
+        let mut rng = rng(seed);
+        let values: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        writeln!(out, "seed_from_u64({}): {:?}", seed, values).unwrap();
+    }
+    let mut seed = [0; 32];
+    seed.iter_mut().zip(1..).for_each(|(b, i)| *b = i);
+    let mut rng = StableRng::from_seed(seed);
+    writeln!(out, "from_seed: {} {}", rng.next_u64(), rng.next_u32()).unwrap();
+    let mut bytes = [0; 11];
+    rng.fill_bytes(&mut bytes);
+    writeln!(out, "fill_bytes: {:?}", bytes).unwrap();
+    check("stable_rng", &out);
+}
+
+#[test]
+#[should_panic]
+fn rng_zero_seed() {
+    StableRng::from_seed([0; 32]);
+}
+
+#[test]
+fn guard() {
+    assert!(!stable::is_enabled());
+    {
+        let _outer = stable::enable();
+        {
+            let _inner = stable::enable();
+            assert!(stable::is_enabled());
+        }
+        assert!(stable::is_enabled());
+        std::thread::spawn(|| assert!(!stable::is_enabled()))
+            .join()
+            .unwrap();
+    }
+    assert!(!stable::is_enabled());
+}
+
+#[test]
+fn primitives() {
+    let mut out = String::new();
+    golden! { out;
+        "bool" => bool = Faker;
+        "char" => char = Faker;
+        "u8" => u8 = Faker;
+        "u16" => u16 = Faker;
+        "u32" => u32 = Faker;
+        "u64" => u64 = Faker;
+        "u128" => u128 = Faker;
+        "usize" => usize = Faker;
+        "i8" => i8 = Faker;
+        "i16" => i16 = Faker;
+        "i32" => i32 = Faker;
+        "i64" => i64 = Faker;
+        "i128" => i128 = Faker;
+        "isize" => isize = Faker;
+        "f32" => f32 = Faker;
+        "f64" => f64 = Faker;
+        "u8 10..20" => u8 = (10..20);
+        "u8 250.." => u8 = (250..);
+        "u32 ..=3" => u32 = (..=3);
+        "i16 -5..=5" => i16 = (-5..=5);
+        "i64 ..0" => i64 = (..0);
+        "u64 1..54683546546434" => u64 = (1u64..54683546546434);
+        "u128 0..10^30" => u128 = (0..10u128.pow(30));
+        "i128 .." => i128 = (..);
+        "usize 0..7" => usize = (0..7);
+        "isize -3..3" => isize = (-3..3);
+        "f32 0..1" => f32 = (0.0..1.0);
+        "f64 -10..=10" => f64 = (-10.0..=10.0);
+        "String" => String = Faker;
+        "(u8, Option<bool>)" => (u8, Option<bool>) = Faker;
+    }
+    check("stable_primitives", &out);
+}
+
+#[test]
+fn fakers() {
+    use fake::faker::address::raw::*;
+    use fake::faker::administrative::raw::*;
+    use fake::faker::automotive::raw::*;
+    use fake::faker::barcode::raw::*;
+    use fake::faker::boolean::raw::*;
+    use fake::faker::company::raw::*;
+    use fake::faker::creditcard::raw::*;
+    use fake::faker::currency::raw::*;
+    use fake::faker::filesystem::raw::*;
+    use fake::faker::finance::raw::*;
+    use fake::faker::internet::raw::*;
+    use fake::faker::job::raw as job;
+    use fake::faker::lorem::raw::*;
+    use fake::faker::name::raw::*;
+    use fake::faker::number::raw::*;
+    use fake::faker::phone_number::raw::*;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let mut out = String::new();
+    golden! { out;
+        "CityPrefix" => String = CityPrefix(EN);
+        "CitySuffix" => String = CitySuffix(EN);
+        "CityName" => String = CityName(EN);
+        "CountryName" => String = CountryName(EN);
+        "CountryCode" => String = CountryCode(EN);
+        "StreetSuffix" => String = StreetSuffix(EN);
+        "StreetName" => String = StreetName(EN);
+        "TimeZone" => String = TimeZone(EN);
+        "StateName" => String = StateName(EN);
+        "StateAbbr" => String = StateAbbr(EN);
+        "SecondaryAddressType" => String = SecondaryAddressType(EN);
+        "SecondaryAddress" => String = SecondaryAddress(EN);
+        "ZipCode" => String = ZipCode(EN);
+        "PostCode" => String = PostCode(EN);
+        "BuildingNumber" => String = BuildingNumber(EN);
+        "Latitude" => String = Latitude(EN);
+        "Longitude" => f64 = Longitude(EN);
+        "Geohash" => String = Geohash(EN, 8);
+        "HealthInsuranceCode(FR_FR)" => String = HealthInsuranceCode(FR_FR);
+        "HealthInsuranceCode(DE_AT)" => String = HealthInsuranceCode(DE_AT);
+        "HealthInsuranceCode(DE_CH)" => String = HealthInsuranceCode(DE_CH);
+        "HealthInsuranceCode(DE_LI)" => String = HealthInsuranceCode(DE_LI);
+        "FiscalCode(IT_IT)" => String = FiscalCode(IT_IT);
+        "TaxIdentificationNumber(DE_DE)" => String = TaxIdentificationNumber(DE_DE);
+        "TaxIdentificationNumber(DE_AT)" => String = TaxIdentificationNumber(DE_AT);
+        "LicencePlate(FR_FR)" => String = LicencePlate(FR_FR);
+        "LicencePlate(IT_IT)" => String = LicencePlate(IT_IT);
+        "LicencePlate(PT_PT)" => String = LicencePlate(PT_PT);
+        "Isbn" => String = Isbn(EN);
+        "Isbn10" => String = Isbn10(EN);
+        "Isbn13" => String = Isbn13(EN);
+        "Boolean" => bool = Boolean(EN, 30);
+        "CompanySuffix" => String = CompanySuffix(EN);
+        "CompanyName" => String = CompanyName(EN);
+        "Buzzword" => String = Buzzword(EN);
+        "BuzzwordMiddle" => String = BuzzwordMiddle(EN);
+        "BuzzwordTail" => String = BuzzwordTail(EN);
+        "CatchPhrase" => String = CatchPhrase(EN);
+        "BsVerb" => String = BsVerb(EN);
+        "BsAdj" => String = BsAdj(EN);
+        "BsNoun" => String = BsNoun(EN);
+        "Bs" => String = Bs(EN);
+        "Profession" => String = Profession(EN);
+        "Industry" => String = Industry(EN);
+        "CreditCardNumber" => String = CreditCardNumber(EN);
+        "CurrencyCode" => String = CurrencyCode(EN);
+        "CurrencyName" => String = CurrencyName(EN);
+        "CurrencySymbol" => String = CurrencySymbol(EN);
+        "FilePath" => String = FilePath(EN);
+        "FileName" => String = FileName(EN);
+        "FileExtension" => String = FileExtension(EN);
+        "DirPath" => PathBuf = DirPath(EN);
+        "MimeType" => String = MimeType(EN);
+        "Semver" => String = Semver(EN);
+        "SemverStable" => String = SemverStable(EN);
+        "SemverUnstable" => String = SemverUnstable(EN);
+        "Bic" => String = Bic(EN);
+        "Isin" => String = Isin(EN);
+        "FreeEmailProvider" => String = FreeEmailProvider(EN);
+        "DomainSuffix" => String = DomainSuffix(EN);
+        "FreeEmail" => String = FreeEmail(EN);
+        "SafeEmail" => String = SafeEmail(EN);
+        "Username" => String = Username(EN);
+        "Password" => String = Password(EN, 8..20);
+        "IPv4" => String = IPv4(EN);
+        "IPv4 addr" => Ipv4Addr = IPv4(EN);
+        "IPv6" => String = IPv6(EN);
+        "IPv6 addr" => Ipv6Addr = IPv6(EN);
+        "IP" => IpAddr = IP(EN);
+        "MACAddress" => String = MACAddress(EN);
+        "UserAgent" => String = UserAgent(EN);
+        "Seniority" => String = job::Seniority(EN);
+        "Field" => String = job::Field(EN);
+        "Position" => String = job::Position(EN);
+        "job::Title" => String = job::Title(EN);
+        "Word" => String = Word(EN);
+        "Words" => Vec<String> = Words(EN, 2..5);
+        "Sentence" => String = Sentence(EN, 2..6);
+        "Sentences" => Vec<String> = Sentences(EN, 1..3);
+        "Paragraph" => String = Paragraph(EN, 1..3);
+        "Paragraphs" => Vec<String> = Paragraphs(EN, 1..3);
+        "FirstName" => String = FirstName(EN);
+        "LastName" => String = LastName(EN);
+        "Title" => String = Title(EN);
+        "Suffix" => String = Suffix(EN);
+        "Name" => String = Name(EN);
+        "NameWithTitle" => String = NameWithTitle(EN);
+        "Digit" => String = Digit(EN);
+        "NumberWithFormat" => String = NumberWithFormat(EN, "^##-####");
+        "PhoneNumber" => String = PhoneNumber(EN);
+        "CellNumber" => String = CellNumber(EN);
+        "Pool" => u32 = fake::pool::Pool::from_values((10..30).collect());
+    }
+    check("stable_fakers", &out);
+}
+
+#[test]
+fn locales() {
+    use fake::faker::address::raw::*;
+    use fake::faker::name::raw::*;
+    use fake::faker::phone_number::raw::*;
+
+    macro_rules! golden_locales {
+        ($out:ident; $($locale:ident),+) => {
+            $(golden! { $out;
+                concat!("Name(", stringify!($locale), ")") => String = Name($locale);
+                concat!("CityName(", stringify!($locale), ")") => String = CityName($locale);
+                concat!("StreetName(", stringify!($locale), ")") => String = StreetName($locale);
+                concat!("ZipCode(", stringify!($locale), ")") => String = ZipCode($locale);
+                concat!("PhoneNumber(", stringify!($locale), ")") => String = PhoneNumber($locale);
+            })+
+        };
+    }
+
+    let mut out = String::new();
+    golden_locales!(out; EN, FR_FR, ZH_TW, ZH_CN, JA_JP, AR_SA, PT_BR, PT_PT, DE_DE, DE_AT, DE_CH, DE_LI, IT_IT);
+    check("stable_locales", &out);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use fake::faker::chrono::raw::*;
+
+    let dt = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let mut out = String::new();
+    golden! { out;
+        "Time" => String = Time(EN);
+        "Date" => String = Date(EN);
+        "DateTime" => String = DateTime(EN);
+        "Duration" => chrono::Duration = Duration(EN);
+        "DateTimeBefore" => String = DateTimeBefore(EN, dt);
+        "DateTimeAfter" => String = DateTimeAfter(EN, dt);
+        "DateTimeBetween" => String = DateTimeBetween(EN, dt, dt + chrono::Duration::days(30));
+    }
+    check("stable_chrono", &out);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time() {
+    use fake::faker::time::raw::*;
+
+    let dt = time::OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+    let mut out = String::new();
+    golden! { out;
+        "Time" => String = Time(EN);
+        "Date" => String = Date(EN);
+        "DateTime" => String = DateTime(EN);
+        "Duration" => time::Duration = Duration(EN);
+        "DateTimeBefore" => String = DateTimeBefore(EN, dt);
+        "DateTimeAfter" => String = DateTimeAfter(EN, dt);
+        "DateTimeBetween" => String = DateTimeBetween(EN, dt, dt + time::Duration::days(30));
+    }
+    check("stable_time", &out);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn http() {
+    use fake::faker::http::raw::*;
+
+    let mut out = String::new();
+    golden! { out;
+        "RfcStatusCode" => String = RfcStatusCode(EN);
+        "ValidStatusCode" => String = ValidStatusCode(EN);
+    }
+    check("stable_http", &out);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() {
+    use fake::Regex;
+
+    let mut out = String::new();
+    golden! { out;
+        "Regex class" => String = Regex("[a-z0-9]{4,8}");
+        "Regex bytes" => String = Regex("(?-u:[\\x00-\\x7f]){3}");
+        "Regex alternation" => String = Regex("(cat|dog|bird)-\\d{2}");
+        "Regex unicode" => String = Regex("[α-ω]+");
+    }
+    check("stable_regex", &out);
+}
@@ -0,0 +1,87 @@
+use fake::faker::job::raw::Title as JobTitle;
+use fake::faker::lorem::raw::Words;
+use fake::faker::name::raw::{FirstName, LastName};
+use fake::locales::{EN, FR_FR};
+use fake::template::TemplateError;
+use fake::{Fake, Template};
+use rand::SeedableRng;
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn expands_like_the_fakers() {
+    let template = Template::parse("{FirstName}.{LastName} is a {job.Title}")
+        .unwrap()
+        .locale(FR_FR);
+    let value: String = template.fake_with_rng(&mut rng());
+
+    let mut rng = rng();
+    let first: String = FirstName(FR_FR).fake_with_rng(&mut rng);
+    let last: String = LastName(FR_FR).fake_with_rng(&mut rng);
+    let title: String = JobTitle(FR_FR).fake_with_rng(&mut rng);
+    assert_eq!(value, format!("{}.{} is a {}", first, last, title));
+}
+
+#[test]
+fn arguments_and_filters() {
+    let template = Template::parse("{{{Words:3..=3|upper}}} {LastName|lower|capitalize}").unwrap();
+    let value: String = template.fake_with_rng(&mut rng());
+
+    let mut rng = rng();
+    let words: Vec<String> = Words(EN, 3..4).fake_with_rng(&mut rng);
+    let last: String = LastName(EN).fake_with_rng(&mut rng);
+    let mut chars = last.to_lowercase().chars().collect::<Vec<_>>();
+    chars[0] = chars[0].to_ascii_uppercase();
+    let last: String = chars.into_iter().collect();
+    assert_eq!(
+        value,
+        format!("{{{}}} {}", words.join(" ").to_uppercase(), last)
+    );
+}
+
+#[test]
+fn formats_and_numbers() {
+    let template = Template::parse("{NumberWithFormat:###-##} {Geohash:4}").unwrap();
+    let value: String = template.fake_with_rng(&mut rng());
+    let (number, hash) = value.split_once(' ').unwrap();
+    assert_eq!(number.len(), 6);
+    assert_eq!(hash.len(), 4);
+}
+
+#[test]
+fn parse_errors() {
+    let err = |s| Template::parse(s).err().unwrap();
+    assert_eq!(err("{Name"), TemplateError::Unclosed(0));
+    assert_eq!(err("a}b"), TemplateError::Unopened(1));
+    assert_eq!(
+        err("{Nope}"),
+        TemplateError::UnknownFaker("Nope".to_string())
+    );
+    assert_eq!(
+        err("{Words}"),
+        TemplateError::InvalidArgument {
+            faker: "Words".to_string(),
+            arg: String::new()
+        }
+    );
+    assert_eq!(
+        err("{Words:5..1}"),
+        TemplateError::InvalidArgument {
+            faker: "Words".to_string(),
+            arg: "5..1".to_string()
+        }
+    );
+    assert_eq!(
+        err("{Name:3}"),
+        TemplateError::InvalidArgument {
+            faker: "Name".to_string(),
+            arg: "3".to_string()
+        }
+    );
+    assert_eq!(
+        err("{Name|shout}"),
+        TemplateError::UnknownFilter("shout".to_string())
+    );
+}
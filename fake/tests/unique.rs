@@ -0,0 +1,95 @@
+use fake::faker::internet::en::Username;
+use fake::utils::WrappedVal;
+use fake::{Dummy, Fake, Faker, Unique, UniqueError};
+use rand::SeedableRng;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+fn rng() -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(0)
+}
+
+#[test]
+fn values_are_distinct() {
+    let mut rng = rng();
+    let usernames = Unique::new(Username());
+    let values: HashSet<String> = (0..200)
+        .map(|_| usernames.fake_with_rng(&mut rng))
+        .collect();
+    assert_eq!(values.len(), 200);
+    assert_eq!(usernames.len(), 200);
+}
+
+#[test]
+fn exhausted_domain_returns_error() {
+    let mut rng = rng();
+    let ids = Unique::new(1..=5).retries(500);
+    let mut values: Vec<u32> = (0..5).map(|_| ids.fake_with_rng(&mut rng)).collect();
+    values.sort_unstable();
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+
+    let err = ids
+        .fake_with_rng::<Result<u32, UniqueError>, _>(&mut rng)
+        .unwrap_err();
+    assert_eq!(err.generated, 5);
+
+    ids.reset();
+    assert!(ids.try_fake_with_rng(&mut rng).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "unique faker exhausted")]
+fn exhausted_domain_panics() {
+    let ids = Unique::new(0..1).retries(10);
+    let _: u8 = ids.fake();
+    let _: u8 = ids.fake();
+}
+
+#[test]
+fn clones_share_session() {
+    let mut rng = rng();
+    let ids = Unique::new(1..1000u64);
+    let other = ids.clone();
+    let a: u64 = ids.fake_with_rng(&mut rng);
+    let b: u64 = other.fake_with_rng(&mut rng);
+    assert_ne!(a, b);
+    assert_eq!(ids.len(), 2);
+}
+
+#[test]
+fn values_are_compared_not_hashes() {
+    // every value hashes the same, yet all 50 are distinct
+    #[derive(Debug, Clone, PartialEq, Eq, Dummy)]
+    struct Colliding(#[dummy(faker = "0..50")] u8);
+
+    impl Hash for Colliding {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            0.hash(state);
+        }
+    }
+
+    let mut rng = rng();
+    let values = Unique::new(Faker).retries(10_000);
+    for _ in 0..50 {
+        let _: WrappedVal<Colliding> = values.fake_with_rng(&mut rng);
+    }
+    assert_eq!(values.len(), 50);
+    assert!(values
+        .fake_with_rng::<Result<Colliding, UniqueError>, _>(&mut rng)
+        .is_err());
+}
+
+#[test]
+fn other_types() {
+    let mut rng = rng();
+    let vecs = Unique::new((0..2u8, 2..3usize));
+    let mut values: Vec<Vec<u8>> = (0..4).map(|_| vecs.fake_with_rng(&mut rng)).collect();
+    values.sort();
+    assert_eq!(values, [[0, 0], [0, 1], [1, 0], [1, 1]]);
+
+    let pairs = Unique::new((0..3u8, 0..3u8));
+    let values: HashSet<(u8, u8)> = (0..9)
+        .map(|_| pairs.try_fake_with_rng(&mut rng).unwrap())
+        .collect();
+    assert_eq!(values.len(), 9);
+}
@@ -0,0 +1,91 @@
+use fake::faker::address::raw::*;
+use fake::faker::company::raw::*;
+use fake::faker::creditcard::raw::*;
+use fake::faker::internet::raw::*;
+use fake::faker::lorem::raw::*;
+use fake::faker::name::raw::*;
+use fake::faker::number::raw::*;
+use fake::faker::phone_number::raw::*;
+use fake::locales::*;
+use fake::{Dummy, Fake, FakeWrite};
+use rand::SeedableRng;
+
+fn rng(seed: u64) -> rand_chacha::ChaCha20Rng {
+    rand_chacha::ChaCha20Rng::seed_from_u64(seed)
+}
+
+/// Writes what the `String` impl generates, after what's already in the buffer.
+fn check<C: FakeWrite>(faker: C)
+where
+    String: Dummy<C>,
+{
+    for seed in 0..20 {
+        let expected: String = faker.fake_with_rng(&mut rng(seed));
+        let mut buf = String::from(">");
+        faker.fake_into(&mut buf, &mut rng(seed)).unwrap();
+        assert_eq!(buf[1..], expected);
+    }
+}
+
+macro_rules! check_locales {
+    ($($faker:ident($($arg:expr),*)),+ $(,)?) => {
+        $(
+            check($faker(EN $(, $arg)*));
+            check($faker(FR_FR $(, $arg)*));
+            check($faker(ZH_CN $(, $arg)*));
+            check($faker(JA_JP $(, $arg)*));
+            check($faker(DE_CH $(, $arg)*));
+            check($faker(Locale::PtBr $(, $arg)*));
+            check($faker(Mixed(&[(Locale::En, 1), (Locale::ZhTw, 1)]) $(, $arg)*));
+        )+
+    };
+}
+
+#[test]
+fn same_as_string() {
+    check_locales!(
+        Word(),
+        Sentence(0..10),
+        Paragraph(1..4),
+        FirstName(),
+        Name(),
+        NameWithTitle(),
+        FreeEmail(),
+        SafeEmail(),
+        Username(),
+        Password(8..20),
+        IPv4(),
+        IPv6(),
+        IP(),
+        MACAddress(),
+        CreditCardNumber(),
+        CityName(),
+        StreetName(),
+        SecondaryAddress(),
+        ZipCode(),
+        BuildingNumber(),
+        Latitude(),
+        Geohash(8),
+        CompanyName(),
+        CatchPhrase(),
+        Bs(),
+        PhoneNumber(),
+        CellNumber(),
+        NumberWithFormat("^##-####"),
+    );
+}
+
+#[test]
+fn buffer_reuse() {
+    let mut rng = rng(0);
+    let mut buf = String::new();
+    for _ in 0..100 {
+        buf.clear();
+        Sentence(EN, 3..6).fake_into(&mut buf, &mut rng).unwrap();
+        buf.push('\t');
+        CreditCardNumber(EN).fake_into(&mut buf, &mut rng).unwrap();
+        let (sentence, number) = buf.split_once('\t').unwrap();
+        assert!(sentence.ends_with('.'));
+        assert!(number.bytes().all(|b| b.is_ascii_digit()));
+    }
+}